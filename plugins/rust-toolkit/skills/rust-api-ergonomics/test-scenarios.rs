@@ -0,0 +1,155 @@
+// Test scenarios for rust-api-ergonomics skill
+// Function signatures that are awkward or error-prone to call
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+// SCENARIO 1: &String parameter
+pub fn is_valid_name(name: &String) -> bool {  // ❌ Literals and slices must allocate
+    !name.is_empty() && name.chars().all(char::is_alphanumeric)
+}
+
+fn caller_1() -> bool {
+    is_valid_name(&"admin".to_string())  // ❌ Allocation just to call
+}
+
+// SCENARIO 2: &Vec<u8> and &Box<T> parameters
+pub fn checksum(data: &Vec<u8>) -> u32 {  // ❌ Should be &[u8]
+    data.iter().map(|&b| b as u32).sum()
+}
+
+pub fn render(node: &Box<Node>) -> String {  // ❌ Should be &Node
+    node.to_string()
+}
+
+// SCENARIO 3: &PathBuf parameter
+pub fn load_config(path: &PathBuf) -> io::Result<String> {  // ❌ Rejects &Path and literals
+    std::fs::read_to_string(path)
+}
+
+// SCENARIO 4: &str immediately cloned
+pub struct User {
+    name: String,
+}
+
+impl User {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_owned() }  // ❌ Forces a clone even when caller owns a String
+    }
+}
+
+// SCENARIO 5: Generic overkill for a conversion
+pub struct Label {
+    text: String,
+}
+
+impl Label {
+    pub fn new<S>(text: S) -> Self
+    where
+        S: AsRef<str> + Into<String> + Clone + std::fmt::Debug,  // ❌ Only Into is used
+    {
+        Self { text: text.into() }
+    }
+}
+
+// SCENARIO 6: Large body monomorphized per caller type
+pub fn import<P: AsRef<Path>>(path: P) -> io::Result<usize> {
+    let path = path.as_ref();
+    let data = std::fs::read(path)?;
+    // ❌ Imagine 200 more lines here, duplicated per P
+    Ok(data.len())
+}
+
+// SCENARIO 7: Out-parameter with bool success flag
+pub fn parse_config(input: &str, out: &mut Config) -> bool {  // ❌ Placeholder needed, no error detail
+    match input.parse() {
+        Ok(c) => {
+            *out = c;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// SCENARIO 8: Multiple out-parameters
+pub fn stats(data: &[f64], mean: &mut f64, min: &mut f64, max: &mut f64) {  // ❌ Return a struct
+    *mean = data.iter().sum::<f64>() / data.len() as f64;
+    *min = data.iter().cloned().fold(f64::INFINITY, f64::min);
+    *max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+}
+
+// SCENARIO 9: Boolean and Option soup
+pub fn export(
+    path: &Path,
+    compress: bool,
+    overwrite: bool,  // ❌ Adjacent bools - swap compiles fine
+    level: Option<u32>,
+    threads: Option<usize>,
+) -> io::Result<()> {
+    todo!()
+}
+
+fn caller_9(out: &Path) -> io::Result<()> {
+    export(out, true, false, None, Some(4))  // ❌ Meaning invisible at call site
+}
+
+// SCENARIO 10: Good - borrowed slice types
+pub fn good_checksum(data: &[u8]) -> u32 {
+    data.iter().map(|&b| b as u32).sum()  // ✅ Accepts Vec, array, slice
+}
+
+pub fn good_load_config(path: impl AsRef<Path>) -> io::Result<String> {
+    std::fs::read_to_string(path)  // ✅ &str, String, Path, PathBuf all work
+}
+
+// SCENARIO 11: Good - impl Into<String> for stored values
+impl User {
+    pub fn named(name: impl Into<String>) -> Self {
+        Self { name: name.into() }  // ✅ No clone when caller passes a String
+    }
+}
+
+// SCENARIO 12: Good - generic shim, non-generic body
+pub fn good_import(path: impl AsRef<Path>) -> io::Result<usize> {
+    fn inner(path: &Path) -> io::Result<usize> {
+        Ok(std::fs::read(path)?.len())  // ✅ Compiled once
+    }
+    inner(path.as_ref())
+}
+
+// SCENARIO 13: Good - returned values
+pub struct Stats {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+pub fn good_stats(data: &[f64]) -> Option<Stats> {
+    if data.is_empty() {
+        return None;  // ✅ Empty input expressible
+    }
+    Some(Stats {
+        mean: data.iter().sum::<f64>() / data.len() as f64,
+        min: data.iter().cloned().fold(f64::INFINITY, f64::min),
+        max: data.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+    })
+}
+
+// SCENARIO 14: Good - named choices
+pub enum Compression {
+    None,
+    Gzip { level: u32 },
+}
+
+pub enum IfExists {
+    Fail,
+    Overwrite,
+}
+
+pub fn good_export(path: &Path, compression: Compression, if_exists: IfExists) -> io::Result<()> {
+    todo!()
+}
+
+fn caller_14(out: &Path) -> io::Result<()> {
+    good_export(out, Compression::Gzip { level: 6 }, IfExists::Fail)  // ✅ Self-describing
+}
@@ -279,3 +279,69 @@ impl RequestHandler {
 
 // ❌ Chain of single-purpose objects adds complexity
 // Better: RequestHandler contains all logic or owns smaller pieces
+
+// SCENARIO 21: Violation of a declared architecture rule
+// CLAUDE.md:
+// ## Architecture Rules
+// - module `api` may not use `sqlx` directly
+//
+// src/api/users.rs
+async fn get_user(pool: &sqlx::PgPool, id: i64) -> Result<User, ApiError> {  // ❌ Violates declared rule
+    let row = sqlx::query_as("SELECT * FROM users WHERE id = $1").bind(id).fetch_one(pool).await?;
+    Ok(row)
+}
+
+// ✅ Goes through the service layer the rule implies
+async fn get_user_good(users: &UserService, id: UserId) -> Result<User, ApiError> {
+    Ok(users.find(id).await?)
+}
+
+// SCENARIO 22: Pass-through newtype with Deref
+pub struct Headers(pub std::collections::HashMap<String, String>);  // ❌ Public field, no methods
+
+impl std::ops::Deref for Headers {  // ❌ Used exactly like the map it wraps
+    type Target = std::collections::HashMap<String, String>;
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+// ✅ Same wrapper earning its place: private field, invariant, behavior
+pub struct GoodHeaders(std::collections::HashMap<String, String>);
+
+impl GoodHeaders {
+    pub fn insert(&mut self, name: &str, value: String) {
+        self.0.insert(name.to_ascii_lowercase(), value);  // ✅ Case-insensitive keys guaranteed
+    }
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+}
+
+// SCENARIO 23: Enum matched the same way in many modules (inverse of scenario 16)
+pub enum Storage {
+    S3(S3Client),
+    Gcs(GcsClient),
+    Local(std::path::PathBuf),
+}
+
+// src/upload.rs
+fn upload(s: &Storage, key: &str, data: &[u8]) -> std::io::Result<()> {
+    match s {  // ❌ Same three arms repeated in download.rs, delete.rs, list.rs, presign.rs
+        Storage::S3(c) => c.put(key, data),
+        Storage::Gcs(c) => c.write(key, data),
+        Storage::Local(dir) => std::fs::write(dir.join(key), data),
+    }
+}
+
+// Better: one trait, one impl per backend; each module calls storage.put(...)
+
+// SCENARIO 24: Write-only and Debug-only fields inflating a struct
+#[derive(Debug)]
+struct Session {
+    id: SessionId,
+    user: UserId,
+    created_at: std::time::Instant,  // ❌ Set in new(), never read
+    last_path: String,               // ❌ Written on every request, only appears in {:?} logs
+    retries: u32,                    // ❌ Incremented, never checked - a missing limit?
+    #[cfg(feature = "legacy-auth")]  // ❌ No "legacy-auth" in [features]; never compiled
+    ldap_dn: String,
+}
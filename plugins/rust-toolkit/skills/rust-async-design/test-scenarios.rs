@@ -178,3 +178,68 @@ async fn backpressure_handled() {
 }
 
 async fn process_item(_: i32) {}
+
+// SCENARIO 13: Shared state that only looks wrong on a map
+struct AppState {
+    sessions: std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<u64, Session>>>,
+    metrics: std::sync::Arc<std::sync::Mutex<u64>>,  // ❌ Locked from every handler; an AtomicU64 would do
+}
+
+async fn reaper(state: AppState, db: Db) {
+    loop {
+        let mut sessions = state.sessions.write().await;
+        for id in expired(&sessions) {
+            db.delete_session(id).await;  // ❌ Write guard held across .await; every request blocks
+            sessions.remove(&id);
+        }
+        drop(sessions);
+        tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+    }
+}
+
+// SCENARIO 14: Non-cancel-safe read inside a looped select!
+async fn read_frames(mut stream: tokio::net::TcpStream, shutdown: CancellationToken) -> std::io::Result<()> {
+    use tokio::io::AsyncReadExt;
+    let mut header = [0u8; 8];
+    let mut tick = tokio::time::interval(std::time::Duration::from_secs(1));
+    loop {
+        tokio::select! {
+            // ❌ No biased; - shutdown can keep losing to ready reads
+            res = stream.read_exact(&mut header) => {  // ❌ Partial header lost whenever tick wins
+                res.unwrap();  // ❌ Error unwrapped in arm (scenario 7)
+                handle_header(&header).await;
+            }
+            _ = tick.tick() => send_heartbeat().await,
+            _ = shutdown.cancelled() => return Ok(()),
+        }
+    }
+}
+
+// SCENARIO 15: Large local held across await, spawned per connection
+async fn serve_conn(mut socket: tokio::net::TcpStream) -> std::io::Result<()> {
+    use tokio::io::AsyncReadExt;
+    let mut buf = [0u8; 128 * 1024];  // ❌ 128 KiB inside the future
+    loop {
+        let n = socket.read(&mut buf).await?;  // ❌ buf live across this await
+        if n == 0 {
+            return Ok(());
+        }
+    }
+}
+
+async fn accept_loop(listener: tokio::net::TcpListener) {
+    while let Ok((socket, _)) = listener.accept().await {
+        tokio::spawn(serve_conn(socket));  // ❌ 128 KiB+ moved to the heap per connection
+    }
+}
+
+// SCENARIO 16: Non-lock resources held across await
+async fn transfer(pool: &sqlx::PgPool, http: &reqwest::Client, id: i64) -> Result<(), Error> {
+    let span = tracing::info_span!("transfer", id);
+    let _entered = span.enter();  // ❌ Entered guard across awaits; other tasks' events land in this span
+    let mut tx = pool.begin().await?;
+    sqlx::query("UPDATE accounts SET locked = true WHERE id = $1").bind(id).execute(&mut *tx).await?;
+    http.post("https://bank.example/transfer").send().await?;  // ❌ Transaction and row lock held across remote call
+    tx.commit().await?;
+    Ok(())
+}
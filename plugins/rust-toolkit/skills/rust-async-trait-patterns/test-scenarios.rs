@@ -0,0 +1,93 @@
+// Test scenarios for rust-async-trait-patterns skill
+// Async traits that should trigger dispatch, Send, and object-safety questions
+
+use async_trait::async_trait;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+// SCENARIO 1: async_trait boxing on a per-frame hot path, static dispatch only
+#[async_trait]
+pub trait FrameDecoder {
+    async fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Frame>, DecodeError>;  // ❌ Box per frame
+}
+
+pub struct Connection<D: FrameDecoder> {
+    decoder: D,  // ❌ Never used as dyn - boxing buys nothing
+}
+
+// SCENARIO 2: Public native async trait with no Send story
+pub trait Store {
+    async fn get(&self, key: &str) -> Option<Bytes>;  // ❌ Future's Send-ness unknown to callers
+}
+
+async fn warm<S: Store + Send + Sync + 'static>(store: Arc<S>) {
+    tokio::spawn(async move { store.get("k").await });  // ❌ error: future cannot be sent between threads
+}
+
+// SCENARIO 3: async_trait forcing Send on single-threaded implementations
+#[async_trait]
+pub trait Renderer {
+    async fn render(&self, scene: &Scene) -> Image;  // ❌ Implicit + Send
+}
+
+struct WebGlRenderer {
+    ctx: std::rc::Rc<WebGlContext>,  // ❌ Can't implement Renderer
+}
+
+// SCENARIO 4: Native async trait used as dyn
+pub trait Handler {
+    async fn handle(&self, req: Request) -> Response;
+}
+
+struct Router {
+    routes: HashMap<String, Box<dyn Handler>>,  // ❌ Not dyn compatible
+}
+
+// SCENARIO 5: Everything boxed for a single dyn use site
+#[async_trait]
+pub trait Plugin: Send + Sync {
+    async fn on_event(&self, e: &Event);  // ❌ Called from 30 generic sites
+    async fn on_tick(&self);
+}
+
+struct Registry {
+    plugins: Vec<Box<dyn Plugin>>,  // The only dyn use
+}
+
+// SCENARIO 6: Hand-written boxed futures duplicated across impls
+trait Fetcher {
+    fn fetch<'a>(&'a self, url: &'a str) -> Pin<Box<dyn Future<Output = Vec<u8>> + 'a>>;  // ❌ No Send
+}
+
+// SCENARIO 7: Good - native async fn with explicit Send
+pub trait GoodStore: Send + Sync {
+    fn get(&self, key: &str) -> impl Future<Output = Option<Bytes>> + Send;  // ✅ Spawnable
+}
+
+// SCENARIO 8: Good - trait_variant for both Send and local variants
+#[trait_variant::make(SendCache: Send)]
+pub trait LocalCache {
+    async fn lookup(&self, key: u64) -> Option<Value>;  // ✅ Two traits, one definition
+}
+
+// SCENARIO 9: Good - dyn companion trait with blanket impl
+pub trait FastHandler: Send + Sync {
+    fn handle(&self, req: Request) -> impl Future<Output = Response> + Send;
+}
+
+pub trait DynHandler: Send + Sync {
+    fn handle_dyn(&self, req: Request) -> Pin<Box<dyn Future<Output = Response> + Send + '_>>;
+}
+
+impl<H: FastHandler> DynHandler for H {
+    fn handle_dyn(&self, req: Request) -> Pin<Box<dyn Future<Output = Response> + Send + '_>> {
+        Box::pin(self.handle(req))  // ✅ Boxing only on the dyn path
+    }
+}
+
+// SCENARIO 10: Good - async_trait(?Send) for single-threaded runtimes
+#[async_trait(?Send)]
+pub trait LocalRenderer {
+    async fn render(&self, scene: &Scene) -> Image;  // ✅ Rc-holding impls allowed
+}
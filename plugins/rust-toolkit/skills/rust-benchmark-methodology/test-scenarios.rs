@@ -0,0 +1,98 @@
+// Test scenarios for rust-benchmark-methodology skill
+// Benchmarks that should trigger optimizer, isolation, and baseline questions
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// SCENARIO 1: Result discarded - work may be optimized away
+fn bench_parse_discarded(c: &mut Criterion) {
+    c.bench_function("parse", |b| {
+        b.iter(|| {
+            parse_config(INPUT);  // ❌ Trailing semicolon, result unused
+        })
+    });
+}
+
+// SCENARIO 2: Constant input - constant folding
+fn bench_fib_constant(c: &mut Criterion) {
+    c.bench_function("fib 20", |b| b.iter(|| fib(20)));  // ❌ No black_box on input
+}
+
+// SCENARIO 3: Setup inside the timed closure
+fn bench_sort_with_setup(c: &mut Criterion) {
+    c.bench_function("sort 10k", |b| {
+        b.iter(|| {
+            let mut data: Vec<u64> = (0..10_000).map(|_| rand::random()).collect();  // ❌ RNG + alloc timed
+            data.sort();
+            data
+        })
+    });
+}
+
+// SCENARIO 4: State carried across iterations
+fn bench_sort_in_place(c: &mut Criterion) {
+    let mut data: Vec<u64> = random_vec(10_000);
+    c.bench_function("sort 10k in place", |b| {
+        b.iter(|| data.sort())  // ❌ Sorted after the first iteration
+    });
+}
+
+// SCENARIO 5: Runtime constructed per iteration
+fn bench_fetch(c: &mut Criterion) {
+    c.bench_function("fetch cached", |b| {
+        b.iter(|| {
+            tokio::runtime::Runtime::new().unwrap().block_on(fetch_cached())  // ❌ Measures runtime startup
+        })
+    });
+}
+
+// SCENARIO 6: Wall-clock assertion in a unit test
+#[test]
+fn parse_is_fast() {
+    let start = Instant::now();
+    for _ in 0..1000 {
+        parse_config(INPUT).unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_millis(50));  // ❌ Debug build, shared CI, flaky
+}
+
+// SCENARIO 7: Toy input size for production-scale code
+fn bench_index_tiny(c: &mut Criterion) {
+    let docs = generate_docs(10);  // ❌ Production indexes millions of documents
+    c.bench_function("build index", |b| b.iter(|| build_index(black_box(&docs))));
+}
+
+// SCENARIO 8: Good - opaque input, returned output
+fn bench_fib(c: &mut Criterion) {
+    c.bench_function("fib 20", |b| b.iter(|| fib(black_box(20))));  // ✅
+}
+
+// SCENARIO 9: Good - fresh input per iteration, setup untimed
+fn bench_sort(c: &mut Criterion) {
+    let input: Vec<u64> = random_vec(10_000);
+    c.bench_function("sort 10k", |b| {
+        b.iter_batched(
+            || input.clone(),  // ✅ Not timed
+            |mut data| {
+                data.sort();
+                data
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+// SCENARIO 10: Good - one runtime, parameterized realistic sizes
+fn bench_parse_sizes(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("parse");
+    for size in [1_000, 100_000, 10_000_000] {
+        let input = generate_input(size);
+        group.throughput(Throughput::Bytes(input.len() as u64));  // ✅ Comparable across sizes
+        group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+            b.to_async(&rt).iter(|| parse_async(black_box(input)))  // ✅ Runtime reused
+        });
+    }
+    group.finish();
+}
@@ -179,3 +179,46 @@ struct Ctx<'a> {
 fn process_ctx<'a>(ctx: &'a Ctx<'a>) -> &'a [u8] {
     ctx.data
 }
+
+// SCENARIO 21: Borrowed field forced on callers by how instances are stored
+struct Request<'a> {
+    path: &'a str,  // ❌ Every caller owns the String anyway
+}
+
+struct Queue<'a> {
+    pending: Vec<Request<'a>>,  // ❌ Lifetime spreads to the queue...
+}
+
+async fn enqueue<'a>(q: &mut Queue<'a>, raw: &'a String) {
+    q.pending.push(Request { path: raw.as_str() });  // ❌ ...and can't be moved into a task
+}
+
+// Better: own it; one allocation per request is negligible here
+struct OwnedRequest {
+    path: String,
+}
+
+// ✅ Keeping the borrow is right here: short-lived views over a large buffer, built per token
+struct Token<'src> {
+    text: &'src str,
+}
+
+fn tokenize(src: &str) -> Vec<Token<'_>> {
+    src.split_whitespace().map(|text| Token { text }).collect()
+}
+
+// SCENARIO 22: Closure bound tied to a struct lifetime
+struct Filter<'a> {
+    keep: Box<dyn Fn(&'a str) -> bool>,  // ❌ Forces 'a onto Filter; can't test loop-local lines
+}
+
+impl<'a> Filter<'a> {
+    fn count(&self, text: &'a str) -> usize {
+        text.lines().filter(|l| (self.keep)(l)).count()
+    }
+}
+
+// Better: higher-ranked, no lifetime on the struct
+struct GoodFilter {
+    keep: Box<dyn Fn(&str) -> bool>,  // ✅ for<'x> Fn(&'x str) -> bool
+}
@@ -0,0 +1,110 @@
+// Test scenarios for rust-c-string-and-encoding skill
+// Text crossing the Rust/C boundary in both directions
+
+use std::ffi::{c_char, CStr, CString, OsStr};
+use std::path::{Path, PathBuf};
+
+// SCENARIO 1: Interior NUL from user input unwrapped
+fn lookup(name: &str) -> Option<User> {
+    let c_name = CString::new(name).unwrap();  // ❌ "\0" in a username panics the handler
+    unsafe { ffi::getpwnam(c_name.as_ptr()).as_ref() }.map(User::from)
+}
+
+// SCENARIO 2: Path through &str into CString
+fn open_file(path: &Path) -> i32 {
+    let c = CString::new(path.to_str().unwrap()).unwrap();  // ❌ Two panics: non-UTF-8, NUL
+    unsafe { libc::open(c.as_ptr(), libc::O_RDONLY) }
+}
+
+// SCENARIO 3: as_ptr on a temporary
+fn set_name(name: &str) -> Result<(), std::ffi::NulError> {
+    let p = CString::new(name)?.as_ptr();  // ❌ CString dropped here
+    unsafe { ffi::set_name(p) };  // ❌ Dangling pointer
+    Ok(())
+}
+
+// SCENARIO 4: Temporary inside an FFI struct literal
+fn open_db(path: &str) -> Result<*mut ffi::Db, std::ffi::NulError> {
+    let opts = ffi::Options {
+        path: CString::new(path)?.as_ptr(),  // ❌ Freed before ffi::open reads it
+        flags: 0,
+    };
+    Ok(unsafe { ffi::open(&opts) })
+}
+
+// SCENARIO 5: C library retains the pointer
+fn set_label(w: *mut ffi::Widget, label: &str) -> Result<(), std::ffi::NulError> {
+    let c = CString::new(label)?;
+    unsafe { ffi::widget_set_label(w, c.as_ptr()) };  // ❌ Widget stores the pointer
+    Ok(())  // ❌ c freed; widget reads it on next draw
+}
+
+// SCENARIO 6: UTF-8 assumed from C
+fn hostname() -> String {
+    let s = unsafe { CStr::from_ptr(ffi::get_hostname()) };
+    s.to_str().unwrap().to_owned()  // ❌ Panics on non-UTF-8 hostname
+}
+
+// SCENARIO 7: from_utf8_unchecked on C data
+fn device_name(p: *const c_char) -> &'static str {
+    unsafe { std::str::from_utf8_unchecked(CStr::from_ptr(p).to_bytes()) }  // ❌ UB if not UTF-8
+}
+
+// SCENARIO 8: C path converted through String
+fn path_from_c(p: *const c_char) -> PathBuf {
+    PathBuf::from(unsafe { CStr::from_ptr(p) }.to_str().unwrap())  // ❌ Loses non-UTF-8 paths
+}
+
+// SCENARIO 9: Capacity vs length confusion
+fn get_name() -> String {
+    let mut buf: Vec<u8> = Vec::with_capacity(256);
+    unsafe { ffi::get_name(buf.as_mut_ptr() as *mut c_char, buf.len()) };  // ❌ len() is 0
+    String::from_utf8(buf).unwrap()
+}
+
+// SCENARIO 10: Returned "needed" length used as written length
+fn format_id(id: u64) -> Vec<u8> {
+    let mut buf = vec![0u8; 16];
+    let n = unsafe { ffi::format_id(buf.as_mut_ptr().cast(), buf.len(), id) };
+    unsafe { buf.set_len(n as usize) };  // ❌ n may exceed 16 on truncation
+    buf
+}
+
+// SCENARIO 11: Good - NulError propagated, named owner
+fn good_lookup(name: &str) -> Result<Option<User>, LookupError> {
+    let c_name = CString::new(name).map_err(|_| LookupError::InvalidName)?;  // ✅
+    Ok(unsafe { ffi::getpwnam(c_name.as_ptr()).as_ref() }.map(User::from))  // ✅ c_name alive
+}
+
+// SCENARIO 12: Good - C string literal
+fn open_readonly(f: *mut ffi::File) {
+    unsafe { ffi::set_mode(f, c"rb".as_ptr()) };  // ✅ Static, no allocation or unwrap
+}
+
+// SCENARIO 13: Good - ownership transferred to C and reclaimed
+fn good_set_label(w: *mut ffi::Widget, label: &str) -> Result<(), std::ffi::NulError> {
+    let raw = CString::new(label)?.into_raw();  // ✅ Widget owns it now
+    unsafe { ffi::widget_set_label_owned(w, raw, free_label) };
+    Ok(())
+}
+
+extern "C" fn free_label(p: *mut c_char) {
+    unsafe { drop(CString::from_raw(p)) };  // ✅ Freed by Rust's allocator
+}
+
+// SCENARIO 14: Good - paths stay as bytes, messages lossy
+fn good_path_from_c(p: *const c_char) -> Option<PathBuf> {
+    use std::os::unix::ffi::OsStrExt;
+    if p.is_null() {
+        return None;
+    }
+    Some(Path::new(OsStr::from_bytes(unsafe { CStr::from_ptr(p) }.to_bytes())).to_owned())  // ✅
+}
+
+fn last_error() -> Option<String> {
+    let p = unsafe { ffi::last_error() };
+    if p.is_null() {
+        return None;
+    }
+    Some(unsafe { CStr::from_ptr(p) }.to_string_lossy().into_owned())  // ✅ Display only
+}
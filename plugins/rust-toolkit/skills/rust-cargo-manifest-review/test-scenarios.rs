@@ -0,0 +1,95 @@
+// Test scenarios for rust-cargo-manifest-review skill
+// Cargo.toml manifests (shown as comments) that should trigger toolchain, dependency, feature, profile, and publishing questions
+
+// SCENARIO 1: Library with no rust-version
+// [package]
+// name = "acme-client"
+// version = "0.4.0"
+// edition = "2021"              ❌ No rust-version; users on old toolchains get opaque errors
+
+// SCENARIO 2: rust-version contradicted by the code
+// [package]
+// rust-version = "1.60"         ❌ No CI job on 1.60
+pub fn first_even(v: &[u32]) -> Option<u32> {
+    let Some(x) = v.iter().copied().find(|x| x % 2 == 0) else {  // ❌ let-else needs 1.65
+        return None;
+    };
+    Some(x)
+}
+
+// SCENARIO 3: Wildcard and open-ended requirements
+// [dependencies]
+// serde = "*"                   ❌ Accepts serde 2.0
+// rand = ">=0.7"                ❌ No upper bound
+
+// SCENARIO 4: Floating git dependency and unexplained patch
+// [dependencies]
+// internal-auth = { git = "https://github.com/acme/auth", branch = "main" }  ❌ Moves with the branch
+//
+// [patch.crates-io]
+// hyper = { path = "../hyper" }                                              ❌ Why? Until when?
+
+// SCENARIO 5: Same dependency at different versions across members
+// crates/api/Cargo.toml:     tokio = "1.28"
+// crates/worker/Cargo.toml:  tokio = "1.35"   ❌ Should come from [workspace.dependencies]
+
+// SCENARIO 6: Library default features pulling in binary concerns
+// [features]
+// default = ["cli", "tokio-runtime", "native-tls"]   ❌ Every downstream crate compiles clap and tokio
+// cli = ["dep:clap"]
+//
+// [dependencies]
+// tokio = { version = "1", features = ["full"] }     ❌ Library forcing all of tokio
+
+// SCENARIO 7: Non-additive feature
+#[cfg(feature = "strict")]
+pub const MAX_RETRIES: u32 = 0;
+#[cfg(not(feature = "strict"))]
+pub const MAX_RETRIES: u32 = 5;  // ❌ Enabling "strict" anywhere in the graph changes behavior for everyone
+
+// SCENARIO 8: panic = "abort" with code relying on unwinding
+// [profile.release]
+// panic = "abort"
+async fn supervise(handle: tokio::task::JoinHandle<()>) {
+    if let Err(e) = handle.await {
+        if e.is_panic() {
+            tracing::error!("worker panicked; restarting");  // ❌ Never reached: the process already aborted
+        }
+    }
+}
+
+// SCENARIO 9: Profile in a non-root member
+// crates/worker/Cargo.toml
+// [profile.release]
+// lto = true                    ❌ Ignored by Cargo; only the workspace root's profiles apply
+
+// SCENARIO 10: Internal crate without publish = false
+// crates/billing-internal/Cargo.toml
+// [package]
+// name = "billing-internal"
+// version = "0.1.0"             ❌ release-plz in this repo publishes every member
+
+// SCENARIO 11: Good - workspace inheritance and pinned sources
+// [workspace.package]
+// edition = "2021"
+// rust-version = "1.74"                                                       ✅ Checked by the msrv CI job
+//
+// [workspace.dependencies]
+// serde = { version = "1.0.190", features = ["derive"] }                       ✅
+// internal-auth = { git = "https://github.com/acme/auth", rev = "3f2a9c1" }  # until 0.3 is released  ✅
+
+// SCENARIO 12: Good - empty default, opt-in extras, trimmed dependency defaults
+// [features]
+// default = []                  ✅ The application enables rustls or native-tls
+// rustls = ["reqwest/rustls-tls"]
+// native-tls = ["reqwest/native-tls"]
+// cli = ["dep:clap"]
+//
+// [dependencies]
+// reqwest = { version = "0.12", default-features = false, features = ["json"] }  ✅
+// tokio = { version = "1", features = ["rt", "net", "time"] }                     ✅
+
+// SCENARIO 13: Good - profile chosen for a server
+// [profile.release]
+// lto = "thin"
+// debug = "line-tables-only"    ✅ Unwinding kept for task isolation; symbols for profiling
@@ -0,0 +1,105 @@
+// Test scenarios for rust-clone-copy-semantics skill
+// Copy/Clone decisions that should trigger cost and meaning questions
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// SCENARIO 1: Copy derived on a large struct
+#[derive(Clone, Copy)]
+pub struct Frame {
+    pixels: [u8; 1024],  // ❌ 1 KiB memcpy on every by-value use
+    timestamp: u64,
+}
+
+fn brightest(frames: &[Frame]) -> Frame {
+    let mut best = frames[0];  // ❌ Copy
+    for f in frames {
+        if f.brightness() > best.brightness() {
+            best = *f;  // ❌ Copy per improvement
+        }
+    }
+    best
+}
+
+// SCENARIO 2: Copy on a public options struct
+#[derive(Clone, Copy)]
+pub struct RequestOptions {  // ❌ Adding a String field later breaks every caller
+    timeout_ms: u64,
+    retries: u8,
+}
+
+// SCENARIO 3: Deep clone per loop iteration
+impl Engine {
+    fn run(&self, requests: Vec<Request>) {
+        for request in requests {
+            let rules = self.rules.clone();  // ❌ Vec<Rule> with Strings, per request
+            handle(request, rules);
+        }
+    }
+}
+
+// SCENARIO 4: Clone to satisfy the borrow checker
+impl Indexer {
+    fn record_all(&mut self) {
+        for (key, value) in self.index.clone() {  // ❌ Clones the whole map
+            self.stats.record(&key, &value);
+        }
+    }
+}
+
+// SCENARIO 5: Expensive clone hidden behind a generic bound
+fn broadcast<T: Clone>(item: T, sinks: &[Sink<T>]) {
+    for sink in sinks {
+        sink.send(item.clone());  // ❌ For T = Document this copies megabytes per sink
+    }
+}
+
+// SCENARIO 6: Arc clone in a spawn loop - the cheap cousin (async scenario 9)
+async fn fan_out(data: Arc<Vec<u8>>) {
+    for i in 0..1000 {
+        let data = data.clone();  // Refcount bump only - prefer Arc::clone(&data) for clarity
+        tokio::spawn(async move { consume(i, &data).await });
+    }
+}
+
+// SCENARIO 7: Unique resource deriving Clone
+#[derive(Clone)]
+pub struct LogWriter {
+    file: Arc<std::fs::File>,
+    bytes_written: u64,  // ❌ Clones share the fd but count independently
+}
+
+// SCENARIO 8: Cloneable cache that silently diverges
+#[derive(Clone, Default)]
+pub struct UserCache {
+    entries: HashMap<UserId, User>,
+}
+
+fn spawn_worker(cache: &UserCache) {
+    let worker_cache = cache.clone();  // ❌ Author expected shared updates
+    std::thread::spawn(move || refresh(worker_cache));
+}
+
+// SCENARIO 9: Good - small Copy value type
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TenantId(u32);  // ✅ 4 bytes, identity-free
+
+// SCENARIO 10: Good - immutable data shared via Arc<[T]>
+pub struct Policy {
+    rules: Arc<[Rule]>,
+}
+
+impl Policy {
+    fn dispatch(&self, requests: Vec<Request>) {
+        for request in requests {
+            let rules = Arc::clone(&self.rules);  // ✅ Visibly cheap
+            std::thread::spawn(move || evaluate(request, &rules));
+        }
+    }
+}
+
+// SCENARIO 11: Good - explicit shared handle instead of cloning state
+#[derive(Clone)]
+pub struct UserCacheHandle {
+    inner: Arc<Mutex<HashMap<UserId, User>>>,  // ✅ Clone means "another handle"
+}
@@ -223,4 +223,46 @@ where
 // Skills needed:
 // 1. rust-type-system (over-genericized, PhantomData unusual)
 // 2. rust-architectural-composition-critique (dependency injection pattern)
-// 3. Maybe: rust-borrowing-complexity (if lifetime issues exist)
+// 3. rust-phantomdata-and-marker-types (PhantomData<E> only feeds a return type)
+// 4. Maybe: rust-borrowing-complexity (if lifetime issues exist)
+
+// SCENARIO 17: Function over several complexity thresholds
+// cyclomatic 18, nesting 6, args 7 → route to rust-design-review and rust-architectural-composition-critique
+fn reconcile_orders(
+    orders: &[Order],
+    payments: &[Payment],
+    refunds: &[Refund],
+    ledger: &mut Ledger,
+    strict: bool,
+    dry_run: bool,
+    notify: Option<&Notifier>,
+) -> Result<Report, ReconcileError> {
+    let mut report = Report::default();
+    for order in orders {
+        if order.cancelled || order.total == 0 {
+            report.skipped.push(order.id);
+            continue;
+        }
+        if let Some(payment) = payments.iter().find(|p| p.order_id == order.id) {
+            if payment.amount != order.total {
+                if strict {
+                    for refund in refunds.iter().filter(|r| r.order_id == order.id) {
+                        if refund.amount + payment.amount == order.total && !dry_run {
+                            ledger.record(order, payment, Some(refund))?;  // ❌ Nesting 6; three concerns in one fn
+                        }
+                    }
+                } else if let Some(n) = notify {
+                    n.mismatch(order, payment)?;
+                }
+            } else if !dry_run {
+                ledger.record(order, payment, None)?;
+            }
+        } else {
+            report.unpaid.push(order.id);
+            if order.is_overdue() && !dry_run {
+                ledger.flag_overdue(order)?;
+            }
+        }
+    }
+    Ok(report)
+}
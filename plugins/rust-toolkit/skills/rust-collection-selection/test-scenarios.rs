@@ -0,0 +1,109 @@
+// Test scenarios for rust-collection-selection skill
+// Collection choices that should trigger complexity and ordering questions
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+// SCENARIO 1: Vec::contains inside a loop
+fn filter_events<'a>(events: &'a [Event], blocked: &Vec<UserId>) -> Vec<&'a Event> {
+    events
+        .iter()
+        .filter(|e| !blocked.contains(&e.user))  // ❌ O(events × blocked)
+        .collect()
+}
+
+// SCENARIO 2: Quadratic dedup
+fn unique_tags(tags: Vec<String>) -> Vec<String> {
+    let mut unique = Vec::new();
+    for tag in tags {
+        if !unique.contains(&tag) {  // ❌ Linear scan per tag
+            unique.push(tag);
+        }
+    }
+    unique
+}
+
+// SCENARIO 3: BTreeMap used only for point lookups
+struct SessionStore {
+    sessions: BTreeMap<SessionId, Session>,  // ❌ Never iterated, never ranged
+}
+
+impl SessionStore {
+    fn get(&self, id: &SessionId) -> Option<&Session> {
+        self.sessions.get(id)
+    }
+}
+
+// SCENARIO 4: Serialized output depends on HashMap iteration order
+fn write_manifest(deps: &HashMap<String, Version>) -> String {
+    deps.iter()
+        .map(|(k, v)| format!("{k} = \"{v}\"\n"))  // ❌ Order changes between runs
+        .collect()
+}
+
+// SCENARIO 5: Test asserting on HashMap key order
+#[test]
+fn registry_lists_names() {
+    let registry = build_registry();
+    let names: Vec<_> = registry.keys().cloned().collect();
+    assert_eq!(names, vec!["alpha", "beta", "gamma"]);  // ❌ Flaky
+}
+
+// SCENARIO 6: Vec used as a FIFO queue
+fn run_jobs(initial: Vec<Job>) {
+    let mut queue = initial;
+    while !queue.is_empty() {
+        let job = queue.remove(0);  // ❌ O(n) shift per pop
+        queue.extend(job.run());
+    }
+}
+
+// SCENARIO 7: Sliding window on Vec
+fn record(window: &mut Vec<f64>, sample: f64) {
+    window.push(sample);
+    if window.len() > 1000 {
+        window.remove(0);  // ❌ Shifts 1000 elements per sample
+    }
+}
+
+// SCENARIO 8: Check-then-insert double lookup
+fn count_words(words: &[String]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in words {
+        if !counts.contains_key(word) {
+            counts.insert(word.clone(), 0);  // ❌ Second lookup
+        }
+        *counts.get_mut(word).unwrap() += 1;  // ❌ Third lookup + unwrap
+    }
+    counts
+}
+
+// SCENARIO 9: Good - tiny bounded collection scanned linearly
+const RESERVED: [&str; 4] = ["self", "super", "crate", "Self"];
+
+fn is_reserved(ident: &str) -> bool {
+    RESERVED.contains(&ident)  // ✅ 4 elements, fixed by the language
+}
+
+// SCENARIO 10: Good - entry API and VecDeque
+fn count_words_entry(words: &[String]) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+    for word in words {
+        *counts.entry(word.as_str()).or_insert(0) += 1;  // ✅ One lookup
+    }
+    counts
+}
+
+fn run_jobs_deque(initial: Vec<Job>) {
+    let mut queue: VecDeque<Job> = initial.into();
+    while let Some(job) = queue.pop_front() {  // ✅ O(1)
+        queue.extend(job.run());
+    }
+}
+
+// SCENARIO 11: Good - BTreeMap for range queries
+fn due_before(deadlines: &BTreeMap<Instant, Vec<JobId>>, now: Instant) -> Vec<JobId> {
+    deadlines
+        .range(..now)  // ✅ Ordering is the point
+        .flat_map(|(_, ids)| ids.iter().copied())
+        .collect()
+}
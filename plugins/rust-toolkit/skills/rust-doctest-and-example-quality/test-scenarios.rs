@@ -0,0 +1,149 @@
+// Test scenarios for rust-doctest-and-example-quality skill
+// Doc comments and examples that should trigger testing, content, and coverage questions
+
+use std::time::Duration;
+
+// SCENARIO 1: ignore hiding a stale example
+/// Connects to the server.
+///
+/// ```ignore
+/// let client = Client::connect("localhost:8080", Duration::from_secs(5));
+/// client.send(b"hello");
+/// ```
+pub fn connect(config: Config) -> Result<Client, Error> {  // ❌ Signature changed; example never compiled
+    todo!()
+}
+
+// SCENARIO 2: no_run on pure computation with assertions
+/// Normalizes a vector to unit length.
+///
+/// ```no_run
+/// let v = mylib::normalize(&[3.0, 4.0]);
+/// assert_eq!(v, [0.6, 0.8]);
+/// ```
+pub fn normalize(v: &[f64]) -> Vec<f64> {  // ❌ Assertion never executed
+    todo!()
+}
+
+// SCENARIO 3: Rust code in a text block
+/// Usage:
+///
+/// ```text
+/// let cache = Cache::new(100);
+/// cache.insert("k", "v");
+/// ```
+pub struct Cache;  // ❌ Not tested at all
+
+// SCENARIO 4: Example unwraps despite the Errors section
+/// Loads configuration.
+///
+/// # Errors
+/// Returns [`ConfigError::Missing`] if the file does not exist; callers
+/// should fall back to defaults in that case.
+///
+/// ```
+/// let cfg = mylib::load_config("app.toml").unwrap();
+/// ```
+pub fn load_config(path: &str) -> Result<Config, ConfigError> {  // ❌ Example contradicts the advice
+    todo!()
+}
+
+// SCENARIO 5: expect on user input in an example
+/// Parses a port.
+///
+/// ```
+/// let port: u16 = std::env::args().nth(1).unwrap().parse().expect("always a number");
+/// ```
+pub fn parse_port(s: &str) -> Option<u16> {  // ❌ Models trusting input
+    s.parse().ok()
+}
+
+// SCENARIO 6: Entry point with no example
+/// A connection pool.
+pub struct Pool;  // ❌ How to build one, get a connection, return it?
+
+impl Pool {
+    /// Creates a pool builder.
+    pub fn builder() -> PoolBuilder {  // ❌ No example, required fields undocumented
+        todo!()
+    }
+}
+
+// SCENARIO 7: Implementable trait without an example impl
+/// Handles a request.
+pub trait Handler {
+    fn handle(&self, req: Request) -> Response;  // ❌ Users must guess
+}
+
+// SCENARIO 8: Panicking function without Panics section
+/// Returns the element at `idx`.
+pub fn at(v: &[u8], idx: usize) -> u8 {
+    v[idx]  // ❌ Panics on out-of-bounds; undocumented
+}
+
+// SCENARIO 9: Feature-gated example never built
+// Cargo.toml
+// [[example]]
+// name = "tls_server"
+// required-features = ["tls"]   ❌ CI runs `cargo test` without --all-features
+//
+// .github/workflows/ci.yml
+// - run: cargo nextest run       ❌ Doctests never run
+
+// SCENARIO 10: Good - no_run with a reason, compiles against the real API
+/// Connects to the server.
+///
+/// ```no_run
+/// # fn main() -> Result<(), mylib::Error> {
+/// use mylib::{Client, Config};
+///
+/// let client = Client::connect(Config::new("localhost:8080"))?;  // needs a running server
+/// client.send(b"hello")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn good_connect(config: Config) -> Result<Client, Error> {  // ✅ Compiled in CI
+    todo!()
+}
+
+// SCENARIO 11: Good - example follows the documented error handling
+/// Loads configuration.
+///
+/// # Errors
+/// Returns [`ConfigError::Missing`] if the file does not exist.
+///
+/// ```
+/// use mylib::{load_config, Config, ConfigError};
+///
+/// let cfg = match load_config("app.toml") {
+///     Ok(cfg) => cfg,
+///     Err(ConfigError::Missing { .. }) => Config::default(),
+///     Err(e) => return Err(e),
+/// };
+/// # Ok::<(), ConfigError>(())
+/// ```
+pub fn good_load_config(path: &str) -> Result<Config, ConfigError> {  // ✅ Copyable pattern
+    todo!()
+}
+
+// SCENARIO 12: Good - Panics section and runnable example
+/// Returns the element at `idx`.
+///
+/// # Panics
+/// Panics if `idx >= v.len()`.
+///
+/// ```
+/// assert_eq!(mylib::good_at(&[1, 2, 3], 1), 2);
+/// ```
+pub fn good_at(v: &[u8], idx: usize) -> u8 {  // ✅
+    v[idx]
+}
+
+// SCENARIO 13: Good - README doctested and lints enforced
+// lib.rs
+// #![doc = include_str!("../README.md")]                                      ✅ README blocks tested
+// #![warn(missing_docs, clippy::missing_errors_doc, clippy::missing_panics_doc)]  ✅
+//
+// ci.yml
+// - run: cargo test --doc --all-features                                      ✅
+// - run: cargo build --examples --all-features                                ✅
@@ -0,0 +1,118 @@
+// Test scenarios for rust-drop-order-and-raii skill
+// Drop impls and guards that should trigger ordering, blocking, and scope questions
+
+use std::mem::ManuallyDrop;
+use std::sync::{Arc, Mutex};
+
+// SCENARIO 1: Dependent FFI handles relying on field order
+struct Query {
+    ctx: DbHandle,     // ❌ Dropped first
+    stmt: StmtHandle,  // ❌ Finalized after its database is closed
+}
+
+// SCENARIO 2: Correct order by accident, undocumented
+struct Renderer {
+    buffer: GpuBuffer,  // ❌ Must drop before device - nothing says so
+    device: GpuDevice,
+}
+
+// SCENARIO 3: Blocking network call in Drop
+impl Drop for Session {
+    fn drop(&mut self) {
+        let _ = self.client.post("/logout").send();  // ❌ Blocking I/O, error discarded
+    }
+}
+
+// SCENARIO 4: Lock taken in Drop
+static REGISTRY: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+struct Worker {
+    id: u64,
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        REGISTRY.lock().unwrap().retain(|&id| id != self.id);  // ❌ Deadlock if caller holds REGISTRY
+    }
+}
+
+fn prune() {
+    let mut registry = REGISTRY.lock().unwrap();
+    let worker = Worker { id: registry[0] };
+    registry.remove(0);
+    drop(worker);  // ❌ Drops while REGISTRY is locked
+}
+
+// SCENARIO 5: let _ = on a lock guard
+fn increment(state: &Mutex<State>) {
+    let _ = state.lock().unwrap();  // ❌ Guard dropped immediately
+    unsafe { GLOBAL_COUNTER += 1 };
+}
+
+// SCENARIO 6: let _ = on a span guard
+async fn handle_request() {
+    let _ = tracing::info_span!("handle_request").entered();  // ❌ Span exits on this line
+    process().await;
+}
+
+// SCENARIO 7: block_on in Drop
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        tokio::runtime::Handle::current().block_on(self.rollback_inner());  // ❌ Panics inside runtime
+    }
+}
+
+// SCENARIO 8: spawn in Drop for async cleanup
+impl Drop for AsyncFile {
+    fn drop(&mut self) {
+        let mut inner = self.inner.take().unwrap();
+        tokio::spawn(async move {
+            inner.flush().await.ok();  // ❌ Fire-and-forget, lost at shutdown
+        });
+    }
+}
+
+// SCENARIO 9: Panicking Drop
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.path).unwrap();  // ❌ Panic during unwind aborts
+    }
+}
+
+// SCENARIO 10: Good - explicit order with ManuallyDrop
+struct OrderedQuery {
+    stmt: ManuallyDrop<StmtHandle>,
+    ctx: ManuallyDrop<DbHandle>,
+}
+
+impl Drop for OrderedQuery {
+    fn drop(&mut self) {
+        // SAFETY: each field dropped exactly once, stmt before ctx.
+        unsafe {
+            ManuallyDrop::drop(&mut self.stmt);  // ✅ Order explicit
+            ManuallyDrop::drop(&mut self.ctx);
+        }
+    }
+}
+
+// SCENARIO 11: Good - dependency made structural with Arc
+struct Buffer {
+    raw: std::ptr::NonNull<ffi::buffer>,
+    ctx: Arc<Context>,  // ✅ Context can't be freed while a buffer lives
+}
+
+// SCENARIO 12: Good - explicit async finalization, Drop detects misuse
+impl GoodTransaction {
+    pub async fn commit(mut self) -> Result<(), DbError> {
+        self.done = true;
+        self.conn.execute("COMMIT").await  // ✅ Error reaches the caller
+    }
+}
+
+impl Drop for GoodTransaction {
+    fn drop(&mut self) {
+        if !self.done {
+            self.conn.mark_needs_rollback();  // ✅ Non-blocking, pool cleans up
+        }
+    }
+}
@@ -244,3 +244,100 @@ impl ConfigError {
         matches!(self, ConfigError::NotFound(_))
     }
 }
+
+// SCENARIO 18: Conversion path with no context on any edge
+#[derive(Debug, thiserror::Error)]
+enum StorageError {
+    #[error("io error")]
+    Io(#[from] std::io::Error),  // keeps source, no path
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ServiceError {
+    #[error("storage failed")]
+    Storage(#[from] StorageError),  // keeps source, no operation
+}
+
+fn load_blob(id: u64) -> Result<Vec<u8>, ServiceError> {
+    Ok(std::fs::read(format!("/data/{id}")).map_err(StorageError::from)?)
+    // ❌ io::Error → StorageError → ServiceError: no edge records the path or blob id
+}
+
+// SCENARIO 19: Same variant handled inconsistently across callers
+async fn sync_job(store: &Store) -> Result<(), StoreError> {
+    loop {
+        match store.fetch().await {
+            Err(StoreError::Timeout) => continue,  // Retried here...
+            other => return other.map(drop),
+        }
+    }
+}
+
+async fn report_job(store: &Store) {
+    if let Err(StoreError::Timeout) = store.fetch().await {
+        tracing::warn!("fetch timed out");  // ❌ ...logged and ignored here
+    }
+}
+
+async fn repair_job(store: &Store) -> Result<(), StoreError> {
+    for _ in 0..3 {
+        match store.fetch().await {
+            Err(StoreError::Corrupt) => continue,  // ❌ Retrying a permanent failure
+            other => return other.map(drop),
+        }
+    }
+    Ok(())
+}
+
+// SCENARIO 20: Unwrap budget by context
+pub fn parse_header(raw: &[u8]) -> Header {
+    let text = std::str::from_utf8(raw).unwrap();  // ❌ Library public path: budget is zero
+    Header::from_str(text).expect("parse failed")  // ❌ Message restates the failure
+}
+
+fn main() {
+    let cfg = Config::load("app.toml").expect("app.toml must exist next to the binary");  // ✅ Startup, states the invariant
+    let re = regex::Regex::new(r"^\d+$").expect("regex literal is valid");  // ✅
+    run(cfg, re);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parses() {
+        let h = super::parse_header(b"a: b");  // ✅ Unlimited in tests
+        assert_eq!(h.name().unwrap(), "a");
+    }
+}
+
+// SCENARIO 21: Result-returning entry point that still panics on bad input
+pub fn parse_record(input: &[u8]) -> Result<Record, ParseError> {
+    let len = input[0] as usize;  // ❌ Panics on empty input
+    let body = &input[1..1 + len];  // ❌ Panics when len exceeds the buffer
+    let depth: u32 = count_open(body) - count_close(body);  // ❌ Underflow: panics in debug, wraps in release
+    Record::new(body, depth)
+}
+
+// ✅ Same entry point with the surface converted to errors
+pub fn parse_record_good(input: &[u8]) -> Result<Record, ParseError> {
+    let (&len, rest) = input.split_first().ok_or(ParseError::Empty)?;
+    let body = rest.get(..len as usize).ok_or(ParseError::Truncated { expected: len as usize, got: rest.len() })?;
+    let depth = count_open(body).checked_sub(count_close(body)).ok_or(ParseError::Unbalanced)?;
+    Record::new(body, depth)
+}
+
+// SCENARIO 22: Error messages that can't be traced back
+#[derive(Debug, thiserror::Error)]
+enum SyncError {
+    #[error("Failed.")]  // ❌ Generic, capitalized, trailing period
+    Remote(#[source] reqwest::Error),
+    #[error("{0}")]  // ❌ Message assembled differently at each site
+    Other(String),
+    #[error("io error: {0}")]  // ❌ Source text printed twice in a report chain
+    Io(#[from] std::io::Error),
+}
+
+fn load(path: &std::path::Path) -> anyhow::Result<Vec<u8>> {
+    anyhow::ensure!(path.exists(), "file not found");  // ❌ Which file?
+    std::fs::read(path).map_err(|_| anyhow::anyhow!("request failed"))  // ❌ Same text used in fetch() and push()
+}
@@ -0,0 +1,146 @@
+// Test scenarios for rust-integration-test-architecture skill
+// tests/ layouts and harness code that should trigger link-time, duplication, service, and data questions
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// SCENARIO 1: Flat tests/ directory - one binary per file
+//
+// tests/
+// ├── api_users.rs       ❌ Each file links the whole crate + dev-deps
+// ├── api_orders.rs
+// ├── api_payments.rs
+// ├── api_refunds.rs
+// ├── db_migrations.rs
+// ├── db_queries.rs
+// └── ... 22 more
+
+// SCENARIO 2: tests/common.rs as a shared module
+// tests/common.rs
+pub fn init_logging() {  // ❌ Compiled as its own (empty) test binary
+    let _ = tracing_subscriber::fmt::try_init();
+}
+
+// tests/api_users.rs
+mod common;  // ❌ Repeated in every file; unused helpers warn per binary
+
+// SCENARIO 3: Setup duplicated and drifting between files
+// tests/api_users.rs
+async fn setup_users() -> (TestServer, sqlx::PgPool) {
+    let pool = sqlx::PgPool::connect("postgres://localhost/test").await.unwrap();
+    sqlx::migrate!().run(&pool).await.unwrap();
+    (TestServer::new(app(pool.clone())).unwrap(), pool)
+}
+
+// tests/api_orders.rs
+async fn setup_orders() -> (TestServer, sqlx::PgPool) {
+    let pool = sqlx::PgPool::connect("postgres://localhost/test").await.unwrap();
+    // ❌ Migrations missing - passes only if users tests ran first
+    (TestServer::new(app(pool.clone())).unwrap(), pool)
+}
+
+// SCENARIO 4: Shared database, no isolation
+#[tokio::test]
+async fn counts_users() {
+    let (_srv, pool) = setup_users().await;
+    let n: i64 = sqlx::query_scalar("SELECT count(*) FROM users").fetch_one(&pool).await.unwrap();
+    assert_eq!(n, 0);  // ❌ Fails when another test inserted a user concurrently
+}
+
+// SCENARIO 5: Hard-coded external service
+#[tokio::test]
+async fn persists_order() {
+    let pool = sqlx::PgPool::connect("postgres://postgres@localhost/app_test")  // ❌ Fresh clone: connection refused
+        .await
+        .unwrap();
+    let _ = pool;
+}
+
+// SCENARIO 6: Silent pass when service is missing
+#[tokio::test]
+async fn uploads_file() {
+    let Ok(client) = S3Client::from_env().await else {
+        return;  // ❌ Reports success in CI when credentials are missing
+    };
+    client.put("k", b"v").await.unwrap();
+}
+
+// SCENARIO 7: #[ignore] with no reason and no runner
+#[test]
+#[ignore]  // ❌ Nobody knows what it needs; never runs
+fn s3_roundtrip() {}
+
+// SCENARIO 8: Sleep-based readiness wait
+#[tokio::test]
+async fn waits_for_container() {
+    std::process::Command::new("docker").args(["compose", "up", "-d"]).status().unwrap();
+    tokio::time::sleep(std::time::Duration::from_secs(10)).await;  // ❌ Too short on CI, too long locally
+}
+
+// SCENARIO 9: CWD-relative fixture and in-tree writes
+#[test]
+fn transforms_document() {
+    let input = fs::read("tests/data/input.json").unwrap();  // ❌ Depends on working directory
+    let out = transform(&input);
+    fs::write("tests/data/output.json", &out).unwrap();  // ❌ Writes into the source tree
+}
+
+// SCENARIO 10: Hand-maintained golden file
+#[test]
+fn renders_doc() {
+    let expected = fs::read_to_string("tests/golden/doc1.html").unwrap();
+    assert_eq!(render(&load_doc()), expected);  // ❌ No regeneration path
+}
+
+// SCENARIO 11: Good - single binary with support module
+//
+// tests/
+// └── integration/
+//     ├── main.rs        ✅ mod api; mod db; mod support;
+//     ├── support/mod.rs
+//     ├── api/{mod,users,orders}.rs
+//     └── db/{mod,migrations}.rs
+
+// SCENARIO 12: Good - shared, isolated harness
+pub struct TestApp {
+    pub server: TestServer,
+    pub db: sqlx::PgPool,
+    _schema: TempSchema,  // ✅ Per-test schema, dropped at end
+}
+
+impl TestApp {
+    pub async fn spawn() -> Self {
+        let (db, schema) = TempSchema::create(&database_url()).await;
+        sqlx::migrate!().run(&db).await.expect("migrations");  // ✅ Always migrated
+        Self { server: TestServer::new(app(db.clone())).expect("server"), db, _schema: schema }
+    }
+}
+
+// SCENARIO 13: Good - opt-in locally, enforced in CI
+pub fn database_url() -> String {
+    match std::env::var("TEST_DATABASE_URL") {
+        Ok(url) => url,
+        Err(_) if std::env::var_os("CI").is_some() => panic!("TEST_DATABASE_URL must be set in CI"),  // ✅
+        Err(_) => {
+            eprintln!("TEST_DATABASE_URL not set; starting a container");
+            start_postgres_container()  // ✅ Works on a fresh clone
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires AWS credentials; runs in the nightly `s3` CI job"]  // ✅ Reason recorded
+fn good_s3_roundtrip() {}
+
+// SCENARIO 14: Good - anchored fixtures, temp output, snapshots
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data").join(name)  // ✅ CWD-independent
+}
+
+#[test]
+fn good_transforms_document() {
+    let input = fs::read(fixture("input.json")).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("output.json"), transform(&input)).unwrap();  // ✅ Temp only
+    insta::assert_snapshot!(render(&load_doc()));  // ✅ `cargo insta review` to update
+}
@@ -0,0 +1,97 @@
+// Test scenarios for rust-lazy-initialization-patterns skill
+// Lazy statics and once-cells that should trigger initialization-timing questions
+
+use std::sync::{LazyLock, OnceLock};
+
+// SCENARIO 1: Fallible initialization panics at first use
+static CONFIG: LazyLock<Config> = LazyLock::new(|| {
+    let text = std::fs::read_to_string("config.toml").unwrap();  // ❌ I/O failure at first access
+    toml::from_str(&text).unwrap()                                // ❌ Parse failure poisons the lazy
+});
+
+async fn handle(req: Request) -> Response {
+    let limit = CONFIG.rate_limit;  // ❌ First request panics, not startup
+    respond(req, limit).await
+}
+
+// SCENARIO 2: Check-then-set double initialization
+static POOL: OnceLock<Pool> = OnceLock::new();
+
+fn pool() -> &'static Pool {
+    if let Some(pool) = POOL.get() {
+        return pool;
+    }
+    let pool = Pool::connect(DATABASE_URL);  // ❌ Runs once per racing thread
+    let _ = POOL.set(pool);                  // ❌ Losing pools silently dropped
+    POOL.get().unwrap()
+}
+
+// SCENARIO 3: Blocking I/O under get_or_init from async code
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+async fn fetch(url: &str) -> Result<Bytes, Error> {
+    let client = CLIENT.get_or_init(|| {
+        let certs = std::fs::read("certs.pem").unwrap();  // ❌ Sync I/O on a runtime worker
+        Client::with_certs(certs)
+    });
+    client.get(url).send().await?.bytes().await
+}
+
+// SCENARIO 4: Configuration captured in a lazy static
+static API_BASE: LazyLock<String> = LazyLock::new(|| {
+    std::env::var("API_BASE")  // ❌ Hidden env dependency, untestable
+        .unwrap_or_else(|_| "https://api.example.com".to_string())
+});
+
+pub fn user_url(id: u64) -> String {
+    format!("{}/users/{}", *API_BASE, id)
+}
+
+#[test]
+fn user_url_points_at_staging() {
+    std::env::set_var("API_BASE", "https://staging.example.com");  // ❌ Order-dependent test
+    assert!(user_url(1).starts_with("https://staging"));
+}
+
+// SCENARIO 5: Legacy macro on a modern toolchain
+lazy_static::lazy_static! {
+    static ref WORD: regex::Regex = regex::Regex::new(r"\w+").unwrap();  // ❌ Use std::sync::LazyLock
+}
+
+// SCENARIO 6: Side-effecting initializer
+static METRICS: LazyLock<Metrics> = LazyLock::new(|| {
+    let metrics = Metrics::default();
+    std::thread::spawn(|| flush_forever());  // ❌ Spawns a thread at an unpredictable moment
+    metrics
+});
+
+// SCENARIO 7: Good - constant data in LazyLock
+static IDENT: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"^[a-zA-Z_][a-zA-Z0-9_]*$").expect("valid regex literal")  // ✅ Programmer error only
+});
+
+#[test]
+fn ident_regex_compiles() {
+    LazyLock::force(&IDENT);  // ✅ Panic would surface in CI
+}
+
+// SCENARIO 8: Good - runtime config set once at startup
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+fn main() -> Result<(), AppError> {
+    let settings = Settings::load("settings.toml")?;  // ✅ Failure reported at startup
+    SETTINGS.set(settings).map_err(|_| AppError::AlreadyInitialized)?;
+    run()
+}
+
+// SCENARIO 9: Good - async initialization with tokio OnceCell
+static ASYNC_CLIENT: tokio::sync::OnceCell<Client> = tokio::sync::OnceCell::const_new();
+
+async fn client() -> Result<&'static Client, Error> {
+    ASYNC_CLIENT
+        .get_or_try_init(|| async {
+            let certs = tokio::fs::read("certs.pem").await?;  // ✅ Async I/O, error propagated
+            Client::with_certs(certs)
+        })
+        .await
+}
@@ -0,0 +1,149 @@
+// Test scenarios for rust-maybeuninit-and-uninitialized-memory skill
+// Code creating memory before it holds a valid value
+
+use std::mem::{self, MaybeUninit};
+use std::ptr::addr_of_mut;
+
+// SCENARIO 1: Zeroed struct with references and Vec (extends systems scenario 6)
+#[repr(C)]
+struct Ctx<'a> {
+    name: &'a str,
+    buf: Vec<u8>,
+    flags: u32,
+}
+
+fn new_ctx<'a>() -> Ctx<'a> {
+    unsafe { mem::zeroed() }  // ❌ Null &str and Vec pointer - UB on creation
+}
+
+// SCENARIO 2: Zeroed enum without a zero discriminant
+#[repr(u8)]
+enum Mode {
+    Read = 1,
+    Write = 2,
+}
+
+fn default_mode() -> Mode {
+    unsafe { mem::zeroed() }  // ❌ 0 is not a valid Mode
+}
+
+// SCENARIO 3: Deprecated mem::uninitialized
+fn scratch() -> [u8; 4096] {
+    #[allow(deprecated)]
+    unsafe { mem::uninitialized() }  // ❌ UB even for u8 arrays
+}
+
+// SCENARIO 4: Zeroed generic T
+fn reset<T>(slot: &mut T) {
+    *slot = unsafe { mem::zeroed() };  // ❌ T may be &U, Box, NonZero...
+}
+
+// SCENARIO 5: FFI out-parameter read without checking the return code
+fn file_size(path: &std::ffi::CStr) -> u64 {
+    let mut st = MaybeUninit::<libc::stat>::uninit();
+    unsafe { libc::stat(path.as_ptr(), st.as_mut_ptr()) };  // ❌ Return value ignored
+    unsafe { st.assume_init() }.st_size as u64  // ❌ Uninitialized on failure
+}
+
+// SCENARIO 6: Field-by-field init through &mut, one field missed
+struct Config {
+    port: u16,
+    host: String,
+    tls: bool,
+}
+
+fn build_config() -> Config {
+    let mut cfg = MaybeUninit::<Config>::uninit();
+    let p = unsafe { &mut *cfg.as_mut_ptr() };  // ❌ Reference to invalid Config
+    p.port = 8080;
+    p.host = String::from("localhost");  // ❌ Assignment drops garbage old String
+    unsafe { cfg.assume_init() }  // ❌ tls never written
+}
+
+// SCENARIO 7: Array filled by closure with no panic handling
+fn build<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> [T; N] {
+    let mut arr: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+    for i in 0..N {
+        arr[i].write(f(i));  // ❌ Panic leaks the prefix
+    }
+    unsafe { mem::transmute_copy(&arr) }
+}
+
+// SCENARIO 8: set_len before writing
+fn names(n: usize) -> Vec<String> {
+    let mut v: Vec<String> = Vec::with_capacity(n);
+    unsafe { v.set_len(n) };  // ❌ n uninitialized Strings inside len
+    for slot in v.iter_mut() {
+        *slot = String::from("x");  // ❌ Drops an uninitialized String
+    }
+    v
+}
+
+// SCENARIO 9: Padded struct written as raw bytes
+#[repr(C)]
+struct Record {
+    tag: u8,
+    id: u32,
+    score: u16,
+}
+
+fn save(rec: &Record, out: &mut impl std::io::Write) -> std::io::Result<()> {
+    let bytes = unsafe {
+        std::slice::from_raw_parts(rec as *const Record as *const u8, mem::size_of::<Record>())
+    };
+    out.write_all(bytes)  // ❌ 5 padding bytes are uninitialized - UB and data leak
+}
+
+// SCENARIO 10: Good - zeroed for a C struct of integers
+#[repr(C)]
+struct SockAddrIn {
+    family: u16,
+    port: u16,
+    addr: u32,
+    zero: [u8; 8],
+}
+
+fn any_addr() -> SockAddrIn {
+    unsafe { mem::zeroed() }  // ✅ All-zero is valid for every field
+}
+
+// SCENARIO 11: Good - checked FFI then assume_init
+fn good_file_size(path: &std::ffi::CStr) -> std::io::Result<u64> {
+    let mut st = MaybeUninit::<libc::stat>::uninit();
+    if unsafe { libc::stat(path.as_ptr(), st.as_mut_ptr()) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    // SAFETY: stat returned 0 and filled `st`
+    Ok(unsafe { st.assume_init() }.st_size as u64)  // ✅
+}
+
+// SCENARIO 12: Good - raw-pointer field writes
+fn good_config() -> Config {
+    let mut cfg = MaybeUninit::<Config>::uninit();
+    let p = cfg.as_mut_ptr();
+    unsafe {
+        addr_of_mut!((*p).port).write(8080);
+        addr_of_mut!((*p).host).write(String::from("localhost"));
+        addr_of_mut!((*p).tls).write(false);  // ✅ Every field written, no references
+    }
+    unsafe { cfg.assume_init() }
+}
+
+// SCENARIO 13: Good - std handles panics during array construction
+fn good_build<T, const N: usize>(f: impl FnMut(usize) -> T) -> [T; N] {
+    std::array::from_fn(f)  // ✅ Drops the prefix on panic
+}
+
+// SCENARIO 14: Good - padding-free byte view checked by bytemuck
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct PackedRecord {
+    id: u32,
+    score: u16,
+    tag: u8,
+    _pad: u8,
+}
+
+fn good_save(rec: &PackedRecord, out: &mut impl std::io::Write) -> std::io::Result<()> {
+    out.write_all(bytemuck::bytes_of(rec))  // ✅ Pod derive rejects implicit padding
+}
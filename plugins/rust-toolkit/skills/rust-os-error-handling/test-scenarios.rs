@@ -0,0 +1,137 @@
+// Test scenarios for rust-os-error-handling skill
+// errno and io::Error handling that should trigger portability, classification, and retry questions
+
+use std::fs;
+use std::io::{self, Read};
+use std::os::fd::RawFd;
+use std::path::Path;
+use std::time::Duration;
+
+// SCENARIO 1: Magic errno number
+fn remove_if_empty(dir: &Path) -> io::Result<()> {
+    match fs::remove_dir(dir) {
+        Err(e) if e.raw_os_error() == Some(39) => Ok(()),  // ❌ ENOTEMPTY on Linux only
+        other => other,
+    }
+}
+
+// SCENARIO 2: Unix constant in cross-platform code
+fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    match fs::rename(from, to) {
+        Err(e) if e.raw_os_error() == Some(libc::EXDEV) => {  // ❌ Doesn't compile on Windows
+            fs::copy(from, to)?;
+            fs::remove_file(from)
+        }
+        other => other,
+    }
+}
+
+// SCENARIO 3: Comment-labelled errno
+fn is_busy(e: &io::Error) -> bool {
+    e.raw_os_error() == Some(16)  // ❌ "EBUSY" - platform-specific, use ErrorKind::ResourceBusy
+}
+
+// SCENARIO 4: Recoverable vs fatal not distinguished (extends error-handling scenario 7)
+fn load_state() -> Result<State, Error> {
+    let bytes = fs::read("state.json")?;  // ❌ Missing file on first run is expected
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+// SCENARIO 5: Retry on any error
+async fn wait_for_file(path: &Path) -> Vec<u8> {
+    loop {
+        match fs::read(path) {
+            Ok(data) => return data,
+            Err(_) => tokio::time::sleep(Duration::from_secs(1)).await,  // ❌ Retries PermissionDenied forever
+        }
+    }
+}
+
+// SCENARIO 6: Errors silently dropped
+fn cleanup(tmp: &Path) {
+    fs::remove_file(tmp).ok();  // ❌ Hides PermissionDenied; NotFound is the only expected case
+}
+
+// SCENARIO 7: Raw read without EINTR retry
+fn read_fd(fd: RawFd, buf: &mut [u8]) -> io::Result<usize> {
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    if n < 0 {
+        return Err(io::Error::last_os_error());  // ❌ EINTR treated as failure
+    }
+    Ok(n as usize)
+}
+
+// SCENARIO 8: errno clobbered before capture
+fn write_fd(fd: RawFd, data: &[u8]) -> io::Result<usize> {
+    let n = unsafe { libc::write(fd, data.as_ptr().cast(), data.len()) };
+    if n < 0 {
+        tracing::warn!(fd, "write failed");  // ❌ May overwrite errno
+        return Err(io::Error::last_os_error());
+    }
+    Ok(n as usize)
+}
+
+// SCENARIO 9: Manual read loop propagates Interrupted
+fn slurp(mut r: impl Read) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = r.read(&mut buf)?;  // ❌ Interrupted is not a real failure
+        if n == 0 {
+            return Ok(out);
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+}
+
+// SCENARIO 10: Busy loop on WouldBlock
+fn read_nonblocking(sock: &mut std::net::TcpStream, buf: &mut [u8]) -> io::Result<usize> {
+    loop {
+        match sock.read(buf) {
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,  // ❌ 100% CPU spin
+            r => return r,
+        }
+    }
+}
+
+// SCENARIO 11: Good - portable ErrorKind
+fn good_remove_if_empty(dir: &Path) -> io::Result<()> {
+    match fs::remove_dir(dir) {
+        Err(e) if e.kind() == io::ErrorKind::DirectoryNotEmpty => Ok(()),  // ✅ Mapped per platform
+        other => other,
+    }
+}
+
+// SCENARIO 12: Good - cfg-gated named constant with fallback
+fn is_cross_device(e: &io::Error) -> bool {
+    if e.kind() == io::ErrorKind::CrossesDevices {
+        return true;
+    }
+    #[cfg(unix)]
+    return e.raw_os_error() == Some(libc::EXDEV);  // ✅ Named, Unix only
+    #[cfg(not(unix))]
+    return false;
+}
+
+// SCENARIO 13: Good - explicit classification, unknown kinds propagate
+fn good_load_state(path: &Path) -> Result<State, Error> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(State::default()),  // ✅ Expected
+        Err(e) => Err(Error::Io { path: path.to_owned(), source: e }),  // ✅ Everything else reported
+    }
+}
+
+// SCENARIO 14: Good - EINTR loop with immediate errno capture
+fn good_read_fd(fd: RawFd, buf: &mut [u8]) -> io::Result<usize> {
+    loop {
+        let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+        if n >= 0 {
+            return Ok(n as usize);
+        }
+        let err = io::Error::last_os_error();  // ✅ Captured first
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// Test scenarios for rust-phantomdata-and-marker-types skill
+// PhantomData and marker types that should trigger variance and design questions
+
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+// SCENARIO 1: Raw-pointer owner without PhantomData<T>
+struct MyBox<T> {
+    ptr: *mut T,  // ❌ Drops a T but drop check doesn't know
+}
+
+impl<T> Drop for MyBox<T> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.ptr)) };
+    }
+}
+
+// SCENARIO 2: PhantomData<*const T> silently removes Send/Sync
+struct Handle<T> {
+    id: u64,
+    _marker: PhantomData<*const T>,  // ❌ Handle<T> is never Send, even for T: Send
+}
+
+// SCENARIO 3: Covariant lifetime brand used by unsafe code
+struct Token<'a> {
+    _marker: PhantomData<&'a ()>,  // ❌ Covariant - a longer-lived token can stand in
+}
+
+struct Arena<'a> {
+    slots: Vec<*mut u8>,
+    _brand: PhantomData<&'a ()>,
+}
+
+impl<'a> Arena<'a> {
+    fn get(&self, _token: Token<'a>, index: usize) -> *mut u8 {
+        unsafe { *self.slots.get_unchecked(index) }  // ❌ Relies on exact brand
+    }
+}
+
+// SCENARIO 4: Typestate where every method is available in every state
+struct Draft;
+struct Ready;
+
+struct Request<S> {
+    url: String,
+    _state: PhantomData<S>,
+}
+
+impl<S> Request<S> {
+    fn send(&self) -> Response { todo!() }  // ❌ Callable on Request<Draft>
+    fn set_url(&mut self, url: String) { self.url = url; }
+}
+
+// SCENARIO 5: Unit markers bypassed through .0
+struct Meters;
+struct Feet;
+struct Length<U>(f64, PhantomData<U>);
+
+fn total(a: Length<Meters>, b: Length<Feet>) -> f64 {
+    a.0 + b.0  // ❌ Adds meters to feet
+}
+
+// SCENARIO 6: Phantom error parameter (review-flow scenario 16)
+struct Handler<T, U, V, E>
+where
+    T: Service,
+    U: Repository,
+    V: Logger,
+    E: std::error::Error,
+{
+    service: T,
+    repo: U,
+    logger: V,
+    error_type: PhantomData<E>,  // ❌ E only appears in handle()'s return type
+}
+
+impl<T: Service, U: Repository, V: Logger, E: std::error::Error> Handler<T, U, V, E> {
+    fn handle(&self) -> Result<Response, E> {
+        todo!()  // ❌ Can't construct an arbitrary E
+    }
+}
+
+// SCENARIO 7: Good - ownership claim and non-null pointer
+struct GoodBox<T> {
+    ptr: NonNull<T>,
+    _owns: PhantomData<T>,  // ✅ Drop check knows GoodBox drops a T
+}
+
+// SCENARIO 8: Good - typed ids that stay Send + Sync + Copy
+pub struct Id<T> {
+    raw: u64,
+    _table: PhantomData<fn() -> T>,  // ✅ Mentions T without owning it
+}
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self { *self }
+}
+impl<T> Copy for Id<T> {}
+
+// SCENARIO 9: Good - per-state impl blocks
+impl Request<Draft> {
+    fn finalize(self) -> Request<Ready> {
+        Request { url: self.url, _state: PhantomData }  // ✅ Transition consumes the draft
+    }
+}
+
+impl Request<Ready> {
+    fn dispatch(&self) -> Response { todo!() }  // ✅ Only Ready requests can be sent
+}
+
+// SCENARIO 10: Good - error type as an associated type
+trait FallibleService {
+    type Error: std::error::Error;
+    fn call(&self) -> Result<Response, Self::Error>;
+}
+
+struct SimpleHandler<S: FallibleService> {
+    service: S,
+}
+
+impl<S: FallibleService> SimpleHandler<S> {
+    fn handle(&self) -> Result<Response, S::Error> {
+        self.service.call()  // ✅ No phantom needed
+    }
+}
+
+// SCENARIO 11: Function parameter used nowhere
+pub fn load_all<T, C: Codec>(dir: &std::path::Path, codec: C) -> Vec<Record> {  // ❌ T unused; callers write load_all::<(), _>
+    read_records(dir, &codec)
+}
+
+// SCENARIO 12: Phantom parameter determined by another parameter
+struct Client<T: Transport<Error = E>, E> {  // ❌ E is always T::Error
+    transport: T,
+    _err: PhantomData<E>,
+}
+
+// Better:
+struct GoodClient<T: Transport> {
+    transport: T,  // ✅ Methods return T::Error
+}
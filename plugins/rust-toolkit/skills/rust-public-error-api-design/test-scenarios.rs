@@ -0,0 +1,128 @@
+// Test scenarios for rust-public-error-api-design skill
+// Library error APIs that should trigger typing, evolution, and usability questions
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+// SCENARIO 1: anyhow in a public library signature
+pub fn open_store(path: &Path) -> anyhow::Result<Store> {  // ❌ Callers can't match failure modes
+    use anyhow::Context;
+    let file = std::fs::File::open(path).context("opening store")?;
+    let header = read_header(&file).context("reading header")?;
+    Ok(Store { file, header })
+}
+
+// SCENARIO 2: Box<dyn Error> without Send + Sync in public API
+pub fn load(path: &Path) -> Result<Config, Box<dyn std::error::Error>> {  // ❌ Can't cross threads
+    todo!()
+}
+
+// SCENARIO 3: Exhaustive public error enum
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {  // ❌ Adding a variant breaks callers' matches
+    #[error("connection refused")]
+    Refused,
+    #[error("bad response")]
+    BadResponse,
+}
+
+// SCENARIO 4: Catch-all variant absorbing growth
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum StoreError {
+    #[error("not found")]
+    NotFound,
+    #[error("{0}")]
+    Other(String),  // ❌ New failure modes become untyped strings
+}
+
+// SCENARIO 5: Third-party error in a public variant
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),  // ❌ reqwest major version is now our API
+}
+
+// SCENARIO 6: Dependency error returned directly
+pub fn parse_manifest(s: &str) -> Result<Manifest, serde_json::Error> {  // ❌ Tied to serde_json
+    serde_json::from_str(s)
+}
+
+// SCENARIO 7: Opaque error with no classification
+pub struct Error {
+    inner: Box<Inner>,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner.message)
+    }
+}
+
+fn caller(err: Error) {
+    if err.to_string().contains("not found") {  // ❌ Forced string matching
+        create_default();
+    }
+}
+
+// SCENARIO 8: Non-Send error type
+#[derive(Debug, thiserror::Error)]
+#[error("validation failed")]
+pub struct ValidationError {
+    context: Rc<Context>,  // ❌ !Send - unusable with anyhow or tokio::spawn
+}
+
+// SCENARIO 9: Borrowed error type
+#[derive(Debug, thiserror::Error)]
+#[error("unexpected token {token}")]
+pub struct ParseError<'a> {
+    token: &'a str,  // ❌ Error can't outlive the input
+}
+
+// SCENARIO 10: Good - typed, non-exhaustive, opaque third-party source
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum GoodFetchError {
+    #[error("request to {url} timed out")]
+    Timeout { url: String },
+    #[error("request to {url} failed")]
+    Transport {
+        url: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,  // ✅ reqwest not exposed
+    },
+}
+
+// SCENARIO 11: Good - opaque error with kind() accessor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    NotFound,
+    PermissionDenied,
+    Corrupt,
+}
+
+#[derive(Debug)]
+pub struct OpaqueError {
+    kind: ErrorKind,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl OpaqueError {
+    pub fn kind(&self) -> ErrorKind {
+        self.kind  // ✅ Callers classify without string matching
+    }
+}
+
+// SCENARIO 12: Good - owned error with auto traits asserted
+#[derive(Debug, thiserror::Error)]
+#[error("unexpected token {token:?} at offset {offset}")]
+pub struct OwnedParseError {
+    token: String,
+    offset: usize,
+}
+
+const _: () = {
+    const fn assert_error<E: std::error::Error + Send + Sync + 'static>() {}
+    assert_error::<OwnedParseError>();  // ✅ Regression caught at compile time
+};
@@ -0,0 +1,94 @@
+// Test scenarios for rust-rayon-data-parallelism skill
+// Rayon usage that should trigger granularity, oversubscription, and contention questions
+
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+// SCENARIO 1: par_iter over a tiny, cheap workload
+fn total(prices: &[u32]) -> u64 {
+    prices.par_iter().map(|&p| p as u64).sum()  // ❌ Cheap per-item work, overhead dominates
+}
+
+// SCENARIO 2: par_iter over a fixed handful of items
+fn shard_sizes(shards: &[Shard; 4]) -> Vec<usize> {
+    shards.par_iter().map(|s| s.len()).collect()  // ❌ 4 trivial jobs
+}
+
+// SCENARIO 3: Nested parallelism
+fn double_all(matrix: &mut Vec<Vec<f64>>) {
+    matrix.par_iter_mut().for_each(|row| {
+        row.par_iter_mut().for_each(|cell| *cell *= 2.0);  // ❌ Inner split fragments work
+    });
+}
+
+// SCENARIO 4: Thread pool built per call
+fn process(batch: &[Item]) -> Vec<Output> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(8)  // ❌ 8 new threads every call, on top of the global pool
+        .build()
+        .unwrap();
+    pool.install(|| batch.par_iter().map(transform).collect())
+}
+
+// SCENARIO 5: Mutex accumulator inside a parallel loop
+fn run_all(inputs: &[Input]) -> Vec<Output> {
+    let results = Mutex::new(Vec::new());
+    inputs.par_iter().for_each(|input| {
+        let out = expensive(input);
+        results.lock().unwrap().push(out);  // ❌ Every worker contends on one lock
+    });
+    results.into_inner().unwrap()
+}
+
+// SCENARIO 6: Shared atomic counter per item
+fn count_known(words: &[String], dict: &HashSet<String>) -> usize {
+    let hits = AtomicUsize::new(0);
+    words.par_iter().for_each(|w| {
+        if dict.contains(w) {
+            hits.fetch_add(1, Ordering::Relaxed);  // ❌ Cache-line ping-pong
+        }
+    });
+    hits.into_inner()
+}
+
+// SCENARIO 7: Rayon blocking inside an async handler
+async fn thumbnails(images: Vec<Image>) -> Vec<Thumbnail> {
+    images.par_iter().map(resize).collect()  // ❌ Blocks the tokio worker thread
+}
+
+// SCENARIO 8: Good - threshold with sequential fallback
+const PAR_THRESHOLD: usize = 10_000;
+
+fn hash_blocks(blocks: &[Block]) -> Vec<Digest> {
+    if blocks.len() < PAR_THRESHOLD {
+        return blocks.iter().map(Block::digest).collect();  // ✅ Small inputs stay sequential
+    }
+    blocks.par_iter().with_min_len(256).map(Block::digest).collect()
+}
+
+// SCENARIO 9: Good - fold/reduce instead of shared state
+fn word_frequencies(words: &[String]) -> HashMap<&str, usize> {
+    words
+        .par_iter()
+        .fold(HashMap::new, |mut m, w| {
+            *m.entry(w.as_str()).or_default() += 1;  // ✅ Per-thread accumulation
+            m
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (k, v) in b {
+                *a.entry(k).or_default() += v;
+            }
+            a
+        })
+}
+
+// SCENARIO 10: Good - async caller hands off to rayon
+async fn thumbnails_offloaded(images: Vec<Image>) -> Result<Vec<Thumbnail>, RecvError> {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    rayon::spawn(move || {
+        let out = images.par_iter().map(resize).collect();
+        let _ = tx.send(out);
+    });
+    rx.await  // ✅ Tokio worker is free while rayon runs
+}
@@ -0,0 +1,167 @@
+// Test scenarios for rust-recursion-and-stack-safety skill
+// Recursion whose depth is controlled by data
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// SCENARIO 1: Recursive descent parser without depth limit
+impl Parser<'_> {
+    fn parse_expr(&mut self) -> Result<Expr, ParseError> {
+        if self.eat('(') {
+            let inner = self.parse_expr()?;  // ❌ "((((...))))" from a request aborts the process
+            self.expect(')')?;
+            return Ok(Expr::Group(Box::new(inner)));
+        }
+        self.parse_atom()
+    }
+}
+
+// SCENARIO 2: serde_json recursion limit disabled
+fn parse_body(body: &str) -> serde_json::Result<serde_json::Value> {
+    use serde::Deserialize;
+    let mut de = serde_json::Deserializer::from_str(body);
+    de.disable_recursion_limit();  // ❌ Removes the 128-level guard on network input
+    serde_json::Value::deserialize(&mut de)
+}
+
+// SCENARIO 3: Recursive evaluation after an iterative parse
+fn eval(e: &Expr) -> f64 {
+    match e {
+        Expr::Num(n) => *n,
+        Expr::Add(a, b) => eval(a) + eval(b),  // ❌ "1+1+1+...+1" is left-nested - depth = length
+        Expr::Group(inner) => eval(inner),
+    }
+}
+
+// SCENARIO 4: Linked list with default recursive Drop
+struct Node {
+    value: i64,
+    next: Option<Box<Node>>,  // ❌ Dropping a long list recurses once per node
+}
+
+fn build(n: usize) -> Option<Box<Node>> {
+    let mut head = None;
+    for i in 0..n {
+        head = Some(Box::new(Node { value: i as i64, next: head }));
+    }
+    head  // ❌ Built iteratively, destroyed recursively
+}
+
+// SCENARIO 5: Deep tree with default Drop
+struct Tree {
+    children: Vec<Tree>,  // ❌ Degenerate (path-shaped) trees overflow on drop
+}
+
+// SCENARIO 6: Recursive DFS over an input graph
+struct Graph {
+    edges: HashMap<u32, Vec<u32>>,
+}
+
+impl Graph {
+    fn visit(&self, id: u32, seen: &mut HashSet<u32>, out: &mut Vec<u32>) {
+        if !seen.insert(id) {
+            return;
+        }
+        for &dep in &self.edges[&id] {
+            self.visit(dep, seen, out);  // ❌ Long dependency chains overflow worker stacks
+        }
+        out.push(id);
+    }
+}
+
+// SCENARIO 7: Recursive directory walk
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            walk(&path, files)?;  // ❌ Depth = directory depth; symlink loops never end
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+// SCENARIO 8: Recursive async fn
+fn crawl(url: String, depth: usize) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>> {
+    Box::pin(async move {
+        for link in fetch_links(&url).await {
+            crawl(link, depth + 1).await;  // ❌ depth tracked but never checked; alloc per level
+        }
+    })
+}
+
+// SCENARIO 9: Bigger stack as the only fix
+fn parse_in_big_thread(input: String) -> Result<Expr, ParseError> {
+    std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)  // ❌ Moves the cliff; doesn't remove it
+        .spawn(move || Parser::new(&input).parse_expr())
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+// SCENARIO 10: Good - depth-limited parser
+const MAX_DEPTH: usize = 256;
+
+impl Parser<'_> {
+    fn good_parse_expr(&mut self, depth: usize) -> Result<Expr, ParseError> {
+        if depth > MAX_DEPTH {
+            return Err(ParseError::TooDeep);  // ✅ Normal error, process survives
+        }
+        if self.eat('(') {
+            let inner = self.good_parse_expr(depth + 1)?;
+            self.expect(')')?;
+            return Ok(Expr::Group(Box::new(inner)));
+        }
+        self.parse_atom()
+    }
+}
+
+// SCENARIO 11: Good - iterative Drop for a linked list
+struct GoodNode {
+    value: i64,
+    next: Option<Box<GoodNode>>,
+}
+
+impl Drop for GoodNode {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(mut node) = next {
+            next = node.next.take();  // ✅ Each node dropped with next = None
+        }
+    }
+}
+
+// SCENARIO 12: Good - explicit work stack
+impl Graph {
+    fn reachable(&self, root: u32) -> HashSet<u32> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![root];
+        while let Some(id) = stack.pop() {
+            if seen.insert(id) {
+                stack.extend(self.edges.get(&id).into_iter().flatten().copied());  // ✅ Heap, not stack
+            }
+        }
+        seen
+    }
+}
+
+// SCENARIO 13: Good - walkdir with depth limit and no link following
+fn good_walk(root: &Path) -> Result<Vec<PathBuf>, walkdir::Error> {
+    walkdir::WalkDir::new(root)
+        .follow_links(false)
+        .max_depth(64)  // ✅ Bounded, loop-safe
+        .into_iter()
+        .map(|e| e.map(|e| e.into_path()))
+        .collect()
+}
+
+// SCENARIO 14: Good - test with pathological input
+#[test]
+fn rejects_deep_nesting() {
+    let input = "(".repeat(100_000) + &")".repeat(100_000);
+    assert!(matches!(Parser::new(&input).good_parse_expr(0), Err(ParseError::TooDeep)));  // ✅
+}
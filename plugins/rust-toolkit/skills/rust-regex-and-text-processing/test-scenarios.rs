@@ -0,0 +1,99 @@
+// Test scenarios for rust-regex-and-text-processing skill
+// Regex usage that should trigger compilation, ReDoS, and simplicity questions
+
+use regex::{Regex, RegexBuilder, RegexSet};
+use std::sync::LazyLock;
+
+// SCENARIO 1: Regex compiled per request
+async fn validate(req: Request) -> Result<(), ApiError> {
+    let email = Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap();  // ❌ Compiled every call
+    if !email.is_match(&req.email) {
+        return Err(ApiError::InvalidEmail);
+    }
+    Ok(())
+}
+
+// SCENARIO 2: Regex compiled inside a loop
+fn error_targets(reader: impl std::io::BufRead) -> Vec<String> {
+    let mut targets = Vec::new();
+    for line in reader.lines().map_while(Result::ok) {
+        let re = Regex::new(r"ERROR \[(\w+)\]").unwrap();  // ❌ Compiled once per line
+        if let Some(c) = re.captures(&line) {
+            targets.push(c[1].to_string());
+        }
+    }
+    targets
+}
+
+// SCENARIO 3: Several regexes against the same input
+fn classify(path: &str) -> Kind {
+    if Regex::new(r"^/api/v\d+/").unwrap().is_match(path) {  // ❌ Compiled per call
+        Kind::Api
+    } else if Regex::new(r"\.(css|js)$").unwrap().is_match(path) {  // ❌ RegexSet candidate
+        Kind::Asset
+    } else {
+        Kind::Page
+    }
+}
+
+// SCENARIO 4: Backtracking engine with nested quantifiers on user input
+fn looks_like_words(comment: &str) -> bool {
+    let re = fancy_regex::Regex::new(r"^(\w+\s?)*$").unwrap();  // ❌ Exponential on crafted input
+    re.is_match(comment).unwrap_or(false)
+}
+
+// SCENARIO 5: User-controlled pattern, unwrapped
+fn search(query: &str, haystack: &str) -> bool {
+    Regex::new(query).unwrap().is_match(haystack)  // ❌ Panics on "(", unbounded compile
+}
+
+// SCENARIO 6: Regex for an anchored literal
+fn is_api(path: &str) -> bool {
+    Regex::new(r"^/api/").unwrap().is_match(path)  // ❌ path.starts_with("/api/")
+}
+
+// SCENARIO 7: Regex split on a fixed separator
+fn fields(line: &str) -> Vec<&str> {
+    Regex::new(",").unwrap().split(line).collect()  // ❌ line.split(',')
+}
+
+// SCENARIO 8: Unescaped metacharacter in a literal-looking pattern
+fn is_config(name: &str) -> bool {
+    Regex::new(r"config.toml$").unwrap().is_match(name)  // ❌ Also matches "configXtoml"
+}
+
+// SCENARIO 9: captures() for a yes/no question
+static LEVEL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(WARN|ERROR)\b").unwrap());
+
+fn is_problem(line: &str) -> bool {
+    LEVEL.captures(line).is_some()  // ❌ is_match() is cheaper
+}
+
+// SCENARIO 10: Good - compiled once, forced in a test
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").expect("valid email regex")  // ✅ Once
+});
+
+#[test]
+fn email_regex_compiles() {
+    LazyLock::force(&EMAIL);
+}
+
+// SCENARIO 11: Good - user pattern escaped and size-limited
+fn search_literal(query: &str, haystack: &str) -> Result<bool, regex::Error> {
+    let re = RegexBuilder::new(&regex::escape(query))
+        .size_limit(1 << 20)  // ✅ Bounded compile
+        .build()?;            // ✅ Error surfaced
+    Ok(re.is_match(haystack))
+}
+
+// SCENARIO 12: Good - RegexSet built from configuration at startup
+struct Filter {
+    patterns: RegexSet,
+}
+
+impl Filter {
+    fn new(patterns: &[String]) -> Result<Self, regex::Error> {
+        Ok(Self { patterns: RegexSet::new(patterns)? })  // ✅ One pass over input for all patterns
+    }
+}
@@ -0,0 +1,84 @@
+// Test scenarios for rust-smallvec-and-inline-storage skill
+// Inline-storage collections that should trigger capacity, spill, and size questions
+
+use arrayvec::ArrayVec;
+use smallvec::SmallVec;
+
+// SCENARIO 1: Inline capacity chosen without measurement
+struct Request {
+    method: Method,
+    headers: SmallVec<[(String, String); 32]>,  // ❌ Why 32? ~1.5 KiB inline per request
+}
+
+// SCENARIO 2: Hot path spills on typical input
+fn split_path(path: &str) -> SmallVec<[&str; 4]> {
+    path.split('/').collect()  // ❌ Typical API paths have 5-7 segments, spills every call
+}
+
+// SCENARIO 3: Extending past capacity in the same call
+fn collect_ids(batch: &[Item]) -> SmallVec<[u64; 8]> {
+    let mut ids = SmallVec::new();
+    ids.extend(batch.iter().map(|item| item.id));  // ❌ Batches are 64-256 items
+    ids
+}
+
+// SCENARIO 4: Large inline buffer in a frequently moved type
+struct Event {
+    kind: EventKind,
+    tags: ArrayVec<[u8; 32], 64>,  // ❌ 2 KiB inline
+}
+
+async fn forward(tx: tokio::sync::mpsc::Sender<Event>, event: Event) {
+    tx.send(event).await.ok();  // ❌ 2 KiB memcpy per hop
+}
+
+// SCENARIO 5: Inline storage in an enum variant inflates every variant
+enum Message {
+    Ping,
+    Ack(u64),
+    Batch(SmallVec<[Record; 16]>),  // ❌ Ping and Ack are now as large as Batch
+}
+
+// SCENARIO 6: Fixed capacity filled from untrusted input
+fn parse_ids(input: &str) -> ArrayVec<u32, 16> {
+    input
+        .split(',')
+        .map(|s| s.parse().unwrap())
+        .collect()  // ❌ Panics on the 17th id
+}
+
+// SCENARIO 7: Same capacity cargo-culted across unrelated types
+struct Span {
+    labels: SmallVec<[Label; 8]>,   // ❌ 8 everywhere, no per-site reasoning
+}
+
+struct Diagnostic {
+    notes: SmallVec<[String; 8]>,
+    spans: SmallVec<[Span; 8]>,     // ❌ Nested inline storage multiplies size
+}
+
+// SCENARIO 8: Good - known size, plain Vec with capacity
+fn collect_ids_sized(batch: &[Item]) -> Vec<u64> {
+    let mut ids = Vec::with_capacity(batch.len());  // ✅ One allocation, no copy
+    ids.extend(batch.iter().map(|item| item.id));
+    ids
+}
+
+// SCENARIO 9: Good - measured capacity with pinned size
+/// 97% of requests carry <= 6 headers (benches/headers.rs).
+struct MeasuredRequest {
+    headers: SmallVec<[(String, String); 6]>,  // ✅ Capacity justified
+}
+
+const _: () = assert!(std::mem::size_of::<MeasuredRequest>() <= 320);  // ✅ Size can't drift
+
+// SCENARIO 10: Good - overflow surfaces as an error
+fn parse_ids_checked(input: &str) -> Result<ArrayVec<u32, 16>, ParseError> {
+    let mut ids = ArrayVec::new();
+    for part in input.split(',') {
+        let id = part.parse().map_err(ParseError::InvalidId)?;
+        ids.try_push(id)
+            .map_err(|_| ParseError::TooManyIds { max: 16 })?;  // ✅ No panic
+    }
+    Ok(ids)
+}
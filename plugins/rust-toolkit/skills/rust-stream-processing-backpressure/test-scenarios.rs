@@ -0,0 +1,121 @@
+// Test scenarios for rust-stream-processing-backpressure skill
+// Pipelines that should trigger queue-bound, slow-consumer, batching, and overload questions
+
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio_stream::{wrappers::ReceiverStream, StreamExt};
+
+// SCENARIO 1: Unbounded channel between Kafka consumer and database writer
+async fn ingest_unbounded(mut consumer: Consumer, db: Db) -> Result<(), Error> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(msg) = consumer.recv().await {
+            tx.send(msg).unwrap();  // ❌ Never waits - backlog moves into memory
+        }
+    });
+    while let Some(msg) = rx.recv().await {
+        db.insert(msg).await?;  // The real bottleneck
+    }
+    Ok(())
+}
+
+// SCENARIO 2: Spawn per stream item
+async fn process_all(mut stream: impl futures::Stream<Item = Job> + Unpin) {
+    while let Some(job) = stream.next().await {
+        tokio::spawn(process(job));  // ❌ Runtime task list is the unbounded queue
+    }
+}
+
+// SCENARIO 3: Sequential sends to subscribers
+async fn fan_out(subscribers: &[Subscriber], event: Event) -> Result<(), Error> {
+    for sub in subscribers {
+        sub.tx.send(event.clone()).await?;  // ❌ One stalled client blocks all
+    }
+    Ok(())
+}
+
+// SCENARIO 4: Broadcast lag unwrapped
+async fn push_events(mut rx: broadcast::Receiver<Event>, mut ws: WebSocket) -> Result<(), Error> {
+    loop {
+        let event = rx.recv().await.unwrap();  // ❌ Lagged panics the connection task
+        ws.send(event).await?;
+    }
+}
+
+// SCENARIO 5: Size-only batching, tail dropped
+async fn batch_writer(mut rx: mpsc::Receiver<Row>, sink: Sink) -> Result<(), Error> {
+    let mut batch = Vec::with_capacity(1_000);
+    while let Some(row) = rx.recv().await {
+        batch.push(row);
+        if batch.len() == 1_000 {  // ❌ At low traffic rows wait for hours
+            sink.write(std::mem::take(&mut batch)).await?;
+        }
+    }
+    Ok(())  // ❌ Partial batch silently dropped
+}
+
+// SCENARIO 6: Ack before durable write
+async fn consume_and_ack(mut consumer: Consumer, tx: mpsc::Sender<Record>) -> Result<(), Error> {
+    while let Some(record) = consumer.recv().await {
+        consumer.ack(record.offset).await?;  // ❌ Acked before the batch is written
+        tx.send(record).await?;
+    }
+    Ok(())
+}
+
+// SCENARIO 7: Request handler blocking on a full queue
+async fn ingest_handler(tx: mpsc::Sender<Event>, e: Event) -> StatusCode {
+    tx.send(e).await.unwrap();  // ❌ Requests pile up until client timeout
+    StatusCode::ACCEPTED
+}
+
+// SCENARIO 8: Silent drop
+fn record_metric(tx: &mpsc::Sender<Sample>, s: Sample) {
+    let _ = tx.try_send(s);  // ❌ Data loss with no counter
+}
+
+// SCENARIO 9: Good - bounded link with size-or-time batching
+async fn good_pipeline(rx: mpsc::Receiver<Row>, sink: Sink) -> Result<(), Error> {
+    let batches = ReceiverStream::new(rx).chunks_timeout(500, Duration::from_millis(250));
+    tokio::pin!(batches);
+    while let Some(batch) = batches.next().await {
+        sink.write(batch).await?;  // ✅ Bounded latency; tail flushed on close
+    }
+    Ok(())
+}
+
+// SCENARIO 10: Good - fast rejection at the edge
+async fn good_ingest_handler(tx: mpsc::Sender<Event>, e: Event) -> StatusCode {
+    match tx.try_send(e) {
+        Ok(()) => StatusCode::ACCEPTED,
+        Err(TrySendError::Full(_)) => {
+            metrics::counter!("ingest_rejected_overload").increment(1);  // ✅ Counted
+            StatusCode::SERVICE_UNAVAILABLE  // ✅ Client can retry elsewhere
+        }
+        Err(TrySendError::Closed(_)) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+// SCENARIO 11: Good - stale work discarded before processing
+async fn worker(mut rx: mpsc::Receiver<Job>) {
+    while let Some(job) = rx.recv().await {
+        if job.deadline < Instant::now() {
+            metrics::counter!("jobs_expired").increment(1);  // ✅ Don't answer nobody
+            continue;
+        }
+        run(job).await;
+    }
+}
+
+// SCENARIO 12: One unbounded edge defeats bounded edges elsewhere
+async fn start_pipeline(listener: tokio::net::TcpListener, db: Db) {
+    let (raw_tx, raw_rx) = tokio::sync::mpsc::channel::<Vec<u8>>(1024);
+    let (parsed_tx, parsed_rx) = tokio::sync::mpsc::unbounded_channel::<Event>();  // ❌ Absorbs all backpressure
+    let (batch_tx, batch_rx) = tokio::sync::mpsc::channel::<Vec<Event>>(8);
+
+    tokio::spawn(accept_loop(listener, raw_tx));
+    tokio::spawn(parser(raw_rx, parsed_tx));      // ❌ Never waits: parsing keeps up, parsed_rx grows without limit
+    tokio::spawn(batcher(parsed_rx, batch_tx));   // Slow because db_writer is slow...
+    tokio::spawn(db_writer(batch_rx, db));        // ...but the pressure stops at the unbounded edge
+}
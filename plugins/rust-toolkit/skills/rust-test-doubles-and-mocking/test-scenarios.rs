@@ -0,0 +1,149 @@
+// Test scenarios for rust-test-doubles-and-mocking skill
+// Testability choices that should trigger fake-vs-mock, trait-justification, and determinism questions
+
+use mockall::predicate::eq;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+// SCENARIO 1: Expectation-heavy mock of a stateful collaborator
+#[test]
+fn renames_user() {
+    let mut repo = MockUserRepo::new();
+    repo.expect_get()
+        .with(eq(UserId(7)))
+        .times(1)  // ❌ Pins the call count, not the outcome
+        .returning(|_| Ok(Some(User { id: UserId(7), name: "a".into() })));
+    repo.expect_save()
+        .withf(|u| u.name == "b")
+        .times(1)
+        .returning(|_| Ok(()));
+
+    rename(&repo, UserId(7), "b").unwrap();  // ❌ No assertion on resulting state
+}
+
+// SCENARIO 2: Same returning closures duplicated across tests
+#[test]
+fn deletes_user() {
+    let mut repo = MockUserRepo::new();
+    repo.expect_get()
+        .returning(|_| Ok(Some(User { id: UserId(7), name: "a".into() })));  // ❌ Copy of scenario 1
+    repo.expect_delete().times(1).returning(|_| Ok(()));
+    delete(&repo, UserId(7)).unwrap();
+}
+
+// SCENARIO 3: Trait exists only for mocking pure logic
+#[cfg_attr(test, mockall::automock)]
+pub trait PriceCalculator {
+    fn total(&self, items: &[Item], coupon: Option<&Coupon>) -> Money;  // ❌ Pure, deterministic
+}
+
+pub struct Checkout<P: PriceCalculator, R: OrderRepo> {
+    pricing: P,  // ❌ Type parameter only MockPriceCalculator needs
+    orders: R,
+}
+
+// SCENARIO 4: Mock returning values the real implementation never would
+#[test]
+fn checkout_applies_coupon() {
+    let mut pricing = MockPriceCalculator::new();
+    pricing.expect_total().returning(|_, _| Money::cents(-500));  // ❌ Impossible negative total
+    let checkout = Checkout { pricing, orders: InMemoryOrders::default() };
+    assert!(checkout.place(vec![], Some(&Coupon::new("HALF"))).is_ok());
+}
+
+// SCENARIO 5: Wall clock in domain logic; test sleeps
+pub struct Session {
+    created: SystemTime,
+    ttl: Duration,
+}
+
+impl Session {
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() > self.created + self.ttl  // ❌ Not controllable from tests
+    }
+}
+
+#[test]
+fn session_expires() {
+    let s = Session { created: SystemTime::now(), ttl: Duration::from_millis(50) };
+    std::thread::sleep(Duration::from_millis(60));  // ❌ Slow, flaky under CI load
+    assert!(s.is_expired());
+}
+
+// SCENARIO 6: Real sleeps in async retry test
+#[tokio::test]
+async fn retries_after_backoff() {
+    let start = std::time::Instant::now();
+    fetch_with_retry(flaky_server()).await.unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(2));  // ❌ Test takes 2+ real seconds
+}
+
+// SCENARIO 7: UUID and RNG inside logic
+pub fn create_order(items: Vec<Item>) -> Order {
+    Order {
+        id: uuid::Uuid::new_v4(),  // ❌ Tests can't predict or assert the ID
+        items,
+        backoff_ms: rand::random::<u64>() % 400 + 100,  // ❌ Unseeded
+    }
+}
+
+// SCENARIO 8: Good - shared in-memory fake, state assertions
+#[derive(Default)]
+pub struct InMemoryUserRepo {
+    users: Mutex<HashMap<UserId, User>>,
+}
+
+impl UserRepo for InMemoryUserRepo {
+    fn get(&self, id: UserId) -> Result<Option<User>, RepoError> {
+        Ok(self.users.lock().unwrap().get(&id).cloned())
+    }
+    fn save(&self, user: &User) -> Result<(), RepoError> {
+        self.users.lock().unwrap().insert(user.id, user.clone());
+        Ok(())
+    }
+    fn delete(&self, id: UserId) -> Result<(), RepoError> {
+        self.users.lock().unwrap().remove(&id);
+        Ok(())
+    }
+}
+
+#[test]
+fn good_renames_user() {
+    let repo = InMemoryUserRepo::default();
+    repo.save(&User { id: UserId(7), name: "a".into() }).unwrap();
+    rename(&repo, UserId(7), "b").unwrap();
+    assert_eq!(repo.get(UserId(7)).unwrap().unwrap().name, "b");  // ✅ Behavior, not calls
+}
+
+// SCENARIO 9: Good - mock where the interaction is the requirement
+#[test]
+fn cached_lookup_does_not_hit_api_twice() {
+    let mut api = MockRatesApi::new();
+    api.expect_fetch().times(1).returning(|_| Ok(Rate(1.1)));  // ✅ Call count is the spec
+    let cache = RateCache::new(api);
+    cache.get("EUR").unwrap();
+    cache.get("EUR").unwrap();
+}
+
+// SCENARIO 10: Good - time passed in
+impl Session {
+    pub fn is_expired_at(&self, now: SystemTime) -> bool {
+        now > self.created + self.ttl  // ✅ Pure
+    }
+}
+
+#[test]
+fn good_session_expires() {
+    let t0 = SystemTime::UNIX_EPOCH;
+    let s = Session { created: t0, ttl: Duration::from_secs(60) };
+    assert!(s.is_expired_at(t0 + Duration::from_secs(61)));  // ✅ Instant, deterministic
+}
+
+// SCENARIO 11: Good - paused tokio time
+#[tokio::test(start_paused = true)]
+async fn good_retries_after_backoff() {
+    let handle = tokio::spawn(fetch_with_retry(flaky_server()));
+    tokio::time::advance(Duration::from_secs(5)).await;  // ✅ No real waiting
+    assert!(handle.await.unwrap().is_ok());
+}
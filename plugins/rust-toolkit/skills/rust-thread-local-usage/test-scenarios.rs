@@ -0,0 +1,119 @@
+// Test scenarios for rust-thread-local-usage skill
+// thread_local! usage that should trigger lifetime, growth, and async-migration questions
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+// SCENARIO 1: Unbounded per-thread cache on pool workers
+thread_local! {
+    static TEMPLATE_CACHE: RefCell<HashMap<String, Template>> = RefCell::new(HashMap::new());
+}
+
+fn render(tenant: &str, ctx: &Context) -> String {
+    TEMPLATE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache
+            .entry(tenant.to_string())  // ❌ One entry per tenant, per worker, forever
+            .or_insert_with(|| load_template(tenant))
+            .render(ctx)
+    })
+}
+
+// SCENARIO 2: Scratch buffer keeps peak capacity
+thread_local! {
+    static SCRATCH: RefCell<Vec<u8>> = RefCell::new(Vec::new());
+}
+
+fn encode(msg: &Message) -> Vec<u8> {
+    SCRATCH.with(|buf| {
+        let mut buf = buf.borrow_mut();
+        buf.clear();  // ❌ One huge message pins that capacity on this worker
+        msg.encode_into(&mut buf);
+        buf.clone()
+    })
+}
+
+// SCENARIO 3: Correctness relies on thread-local destructor
+struct MetricBuffer(Vec<Sample>);
+
+impl Drop for MetricBuffer {
+    fn drop(&mut self) {
+        send_to_collector(&self.0);  // ❌ Not guaranteed to run at process exit
+    }
+}
+
+thread_local! {
+    static METRICS: RefCell<MetricBuffer> = RefCell::new(MetricBuffer(Vec::new()));
+}
+
+// SCENARIO 4: Request context in a thread-local across an await
+thread_local! {
+    static REQUEST_ID: Cell<u64> = Cell::new(0);
+}
+
+async fn handle(req: Request) -> Response {
+    REQUEST_ID.with(|id| id.set(req.id));
+    let user = load_user(req.user_id).await;  // ❌ Task may resume on another worker
+    log::info!("request {}", REQUEST_ID.with(|id| id.get()));  // ❌ Reads another task's id
+    render_user(user)
+}
+
+// SCENARIO 5: Reentrant RefCell borrow through callbacks
+thread_local! {
+    static LISTENERS: RefCell<Vec<Box<dyn Fn(&Event)>>> = RefCell::new(Vec::new());
+}
+
+fn emit(event: &Event) {
+    LISTENERS.with(|listeners| {
+        for listener in listeners.borrow().iter() {
+            listener(event);  // ❌ Listener calling subscribe() panics with BorrowMutError
+        }
+    });
+}
+
+// SCENARIO 6: Destructor touching another thread-local
+struct Session;
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        REQUEST_ID.with(|id| id.set(0));  // ❌ May panic if REQUEST_ID is already destroyed
+    }
+}
+
+thread_local! {
+    static SESSION: RefCell<Option<Session>> = RefCell::new(None);
+}
+
+// SCENARIO 7: Good - task-local request context
+tokio::task_local! {
+    static TASK_REQUEST_ID: u64;
+}
+
+async fn handle_scoped(req: Request) -> Response {
+    TASK_REQUEST_ID
+        .scope(req.id, async move {
+            let user = load_user(req.user_id).await;
+            log::info!("request {}", TASK_REQUEST_ID.get());  // ✅ Follows the task
+            render_user(user)
+        })
+        .await
+}
+
+// SCENARIO 8: Good - bounded scratch buffer with const initializer
+const MAX_RETAINED: usize = 64 * 1024;
+
+thread_local! {
+    static BOUNDED_SCRATCH: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+fn with_scratch<R>(f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+    BOUNDED_SCRATCH.with(|buf| {
+        let mut buf = buf.borrow_mut();
+        buf.clear();
+        let result = f(&mut buf);
+        if buf.capacity() > MAX_RETAINED {
+            buf.shrink_to(MAX_RETAINED);  // ✅ Outliers don't stay resident
+        }
+        result
+    })
+}
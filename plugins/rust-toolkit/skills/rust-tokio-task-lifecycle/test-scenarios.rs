@@ -0,0 +1,141 @@
+// Test scenarios for rust-tokio-task-lifecycle skill
+// Spawned tasks that should trigger ownership, lifetime, observability, and limit questions
+
+use std::sync::Arc;
+use tokio::sync::{RwLock, Semaphore};
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_util::task::TaskTracker;
+use tracing::Instrument;
+
+// SCENARIO 1: Fire-and-forget spawns in a loop (extends async scenario 8)
+async fn reindex_all(shards: Vec<Shard>) {
+    for shard in shards {
+        tokio::spawn(async move {
+            reindex(shard).await;  // ❌ Handle dropped - failures and panics invisible
+        });
+    }
+    // ❌ Returns while reindexing is still running
+}
+
+// SCENARIO 2: Vec<JoinHandle> awaited in order
+async fn reindex_ordered(shards: Vec<Shard>) -> Result<(), Error> {
+    let handles: Vec<_> = shards.into_iter().map(|s| tokio::spawn(reindex_checked(s))).collect();
+    for h in handles {
+        h.await??;  // ❌ A slow first shard delays seeing a failed last shard
+    }
+    Ok(())
+}
+
+// SCENARIO 3: Background task detached from its owner
+pub struct Cache {
+    inner: Arc<RwLock<HashMap<Key, Value>>>,
+    _refresher: JoinHandle<()>,  // ❌ Dropping Cache does not stop the task
+}
+
+impl Cache {
+    pub fn new(source: Source) -> Self {
+        let inner = Arc::new(RwLock::new(HashMap::new()));
+        let refresher = tokio::spawn(refresh_loop(inner.clone(), source));  // ❌ Loop never exits
+        Self { inner, _refresher: refresher }
+    }
+}
+
+// SCENARIO 4: Long-lived anonymous task with no span
+async fn start_reader(conn: Connection) {
+    tokio::spawn(async move {
+        loop {
+            let frame = conn.read_frame().await.unwrap();  // ❌ Panic says only file:line
+            handle_frame(frame).await;                       // ❌ Logs lack connection identity
+        }
+    });
+}
+
+// SCENARIO 5: Task per connection with no limit (extends async scenario 3)
+async fn accept_loop(listener: tokio::net::TcpListener, db: Db) -> std::io::Result<()> {
+    loop {
+        let (socket, _) = listener.accept().await?;
+        tokio::spawn(handle_socket(socket, db.clone()));  // ❌ Unbounded concurrent tasks
+    }
+}
+
+// SCENARIO 6: Semaphore acquired inside the task
+async fn accept_loop_late_permit(listener: tokio::net::TcpListener, sem: Arc<Semaphore>) -> std::io::Result<()> {
+    loop {
+        let (socket, _) = listener.accept().await?;
+        let sem = sem.clone();
+        tokio::spawn(async move {
+            let _permit = sem.acquire().await.unwrap();  // ❌ Tasks still pile up waiting
+            handle_socket_simple(socket).await
+        });
+    }
+}
+
+// SCENARIO 7: Good - JoinSet with completion-order joining
+async fn reindex_joinset(shards: Vec<Shard>) -> Result<(), Error> {
+    let mut set = JoinSet::new();
+    for shard in shards {
+        set.spawn(reindex_checked(shard));
+    }
+    while let Some(res) = set.join_next().await {
+        res??;  // ✅ First failure returns; dropping the set aborts the rest
+    }
+    Ok(())
+}
+
+// SCENARIO 8: Good - abort on drop
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();  // ✅ Task can't outlive its owner
+    }
+}
+
+// SCENARIO 9: Good - permit before spawn, tracked, instrumented
+async fn serve(listener: tokio::net::TcpListener) -> std::io::Result<()> {
+    let limit = Arc::new(Semaphore::new(1_024));
+    let tracker = TaskTracker::new();
+    loop {
+        let permit = limit.clone().acquire_owned().await.expect("never closed");  // ✅ Backpressure on accept
+        let (socket, addr) = listener.accept().await?;
+        tracker.spawn(
+            async move {
+                let _permit = permit;  // ✅ Held for the task's lifetime
+                handle_socket_simple(socket).await
+            }
+            .instrument(tracing::info_span!("conn", %addr)),  // ✅ Identifiable
+        );
+    }
+}
+
+// SCENARIO 10: Spawn hidden behind a per-message call (census row: per item, dropped, none)
+async fn on_message(msg: Message, store: Arc<Store>) {
+    tokio::spawn(async move { store.index(msg).await });  // ❌ Looks like a one-off here...
+}
+
+async fn consume(mut rx: tokio::sync::mpsc::Receiver<Message>, store: Arc<Store>) {
+    while let Some(msg) = rx.recv().await {
+        on_message(msg, store.clone()).await;  // ❌ ...but runs once per message, unbounded
+    }
+}
+
+// SCENARIO 11: Shutdown signal that can't reach a task
+async fn run(shutdown: CancellationToken) {
+    let tracker = TaskTracker::new();
+    tracker.spawn(tail_audit_log());  // ❌ No token: this loop never ends
+
+    let token = shutdown.clone();
+    tracker.spawn(async move {
+        loop {
+            if token.is_cancelled() {
+                break;
+            }
+            flush_metrics().await;
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;  // ❌ Up to 60s before the check runs
+        }
+    });
+
+    shutdown.cancelled().await;
+    tracker.close();
+    tracker.wait().await;  // ❌ No timeout; hangs on tail_audit_log forever
+}
@@ -0,0 +1,128 @@
+// Test scenarios for rust-transmute-audit skill
+// One site per reinterpretation class; each should appear in the audit catalogue
+
+use std::mem;
+
+// SCENARIO 1: Int <-> float via transmute (class A)
+fn float_bits(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }  // ❌ Sound but needless unsafe - use to_bits()
+}
+
+// SCENARIO 2: Lifetime extension into a cache (class B)
+struct Interner {
+    cache: std::collections::HashSet<&'static str>,
+}
+
+impl Interner {
+    fn intern(&mut self, s: &str) -> &'static str {
+        let s: &'static str = unsafe { mem::transmute(s) };  // ❌ Dangles when caller frees s
+        self.cache.insert(s);
+        s
+    }
+}
+
+// SCENARIO 3: Untrusted byte to enum (class C)
+#[repr(u8)]
+enum Opcode {
+    Get = 1,
+    Put = 2,
+    Del = 3,
+}
+
+fn decode_op(buf: &[u8]) -> Opcode {
+    unsafe { mem::transmute(buf[0]) }  // ❌ UB for 0 and 4..=255
+}
+
+// SCENARIO 4: Byte buffer viewed as struct (class D)
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u32,
+}
+
+fn header(buf: &[u8]) -> &Header {
+    unsafe { &*(buf.as_ptr() as *const Header) }  // ❌ No length or alignment check
+}
+
+// SCENARIO 5: Vec transmute (class E)
+fn to_unsigned(v: Vec<i32>) -> Vec<u32> {
+    unsafe { mem::transmute(v) }  // ❌ Vec layout unspecified
+}
+
+// SCENARIO 6: Slice transmute with different element size (class E)
+fn as_words(bytes: &[u8]) -> &[u32] {
+    unsafe { mem::transmute(bytes) }  // ❌ Length stays in bytes - 4x out of bounds
+}
+
+// SCENARIO 7: dlsym to function pointer without null check (class F)
+fn load_add_one(h: *mut libc::c_void) -> extern "C" fn(i32) -> i32 {
+    unsafe { mem::transmute(libc::dlsym(h, c"add_one".as_ptr())) }  // ❌ Null and signature unchecked
+}
+
+// SCENARIO 8: Cast between structs without repr(C) (class G)
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+struct Vec2 {
+    x: f64,
+    y: f64,
+}
+
+fn as_vec2(p: &Point) -> &Vec2 {
+    unsafe { &*(p as *const Point as *const Vec2) }  // ❌ Field order not guaranteed
+}
+
+// SCENARIO 9: transmute_copy with mismatched sizes and invalid bit patterns
+fn first_u64(b: &[u8; 4]) -> u64 {
+    unsafe { mem::transmute_copy(b) }  // ❌ Source smaller than target: panics (size assert since Rust 1.64)
+}
+
+fn flag_at(b: &[u8; 16], i: usize) -> bool {
+    unsafe { mem::transmute_copy(&b[i]) }  // ❌ Same size, but any byte other than 0 or 1 is UB as bool
+}
+
+// SCENARIO 10: Good - safe float bit conversion
+fn good_float_bits(x: f32) -> u32 {
+    x.to_bits()  // ✅ No unsafe
+}
+
+// SCENARIO 11: Good - checked enum conversion
+impl TryFrom<u8> for Opcode {
+    type Error = u8;
+    fn try_from(b: u8) -> Result<Self, u8> {
+        match b {
+            1 => Ok(Opcode::Get),
+            2 => Ok(Opcode::Put),
+            3 => Ok(Opcode::Del),
+            other => Err(other),  // ✅ Unknown values rejected
+        }
+    }
+}
+
+// SCENARIO 12: Good - zerocopy byte view
+#[derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)]
+#[repr(C)]
+struct GoodHeader {
+    magic: zerocopy::little_endian::U32,
+    len: zerocopy::little_endian::U32,
+}
+
+fn good_header(buf: &[u8]) -> Option<&GoodHeader> {
+    GoodHeader::ref_from_prefix(buf).ok().map(|(h, _)| h)  // ✅ Size, alignment, validity checked
+}
+
+// SCENARIO 13: Good - checked slice cast
+fn good_as_words(bytes: &[u8]) -> Result<&[u32], bytemuck::PodCastError> {
+    bytemuck::try_cast_slice(bytes)  // ✅ Length and alignment verified
+}
+
+// SCENARIO 14: Good - repr(transparent) wrapper
+#[derive(bytemuck::TransparentWrapper)]
+#[repr(transparent)]
+struct Meters(f64);
+
+fn as_meters(raw: &[f64]) -> &[Meters] {
+    bytemuck::TransparentWrapper::wrap_slice(raw)  // ✅ Layout guaranteed
+}
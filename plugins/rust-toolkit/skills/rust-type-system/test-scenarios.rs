@@ -77,3 +77,96 @@ fn process_handlers(handlers: Vec<Box<dyn Fn() -> String>>) {
         println!("{}", handler());
     }
 }
+
+// SCENARIO 11: Over the type-parameter and where-clause thresholds
+// type params: 4, where predicates: 4 (lifetimes 0 and bound depth 2 stay under)
+pub fn run_pipeline<S, P, V, E>(source: S, parser: P, validate: V) -> Result<Vec<Record>, E>
+where
+    S: Iterator<Item = String> + Send,
+    P: Fn(&str) -> Result<Record, E> + Send + Sync,
+    V: Fn(&Record) -> bool + Send + Sync,
+    E: std::error::Error + Send + Sync + 'static,  // ❌ Four params and four predicates for a map-filter-collect
+{
+    source.map(|line| parser(&line)).filter(|r| r.as_ref().map_or(true, &validate)).collect()
+}
+
+// SCENARIO 12: Extension-method workarounds for scenario 7
+pub trait VecExt<T> {  // ❌ One method, one implementor, public API surface
+    fn duplicate_first(&self) -> Option<T>;
+}
+
+impl<T: Clone> VecExt<T> for Vec<T> {  // ❌ Excludes &[T] and arrays
+    fn duplicate_first(&self) -> Option<T> {
+        self.first().cloned()
+    }
+}
+
+pub struct Names(pub Vec<String>);  // ❌ Wrapper adds no invariant; callers use .0 everywhere
+
+impl std::ops::Deref for Names {
+    type Target = Vec<String>;
+    fn deref(&self) -> &Vec<String> { &self.0 }
+}
+
+// Better:
+fn duplicate_first<T: Clone>(items: &[T]) -> Option<T> {  // ✅ Free function over a slice
+    items.first().cloned()
+}
+
+// SCENARIO 13: Trait object called per element in a hot loop
+trait Filter {
+    fn apply(&self, px: u8) -> u8;
+}
+
+fn apply_filter(filter: &dyn Filter, image: &mut [u8]) {
+    for px in image.iter_mut() {
+        *px = filter.apply(*px);  // ❌ Same filter every iteration; vtable call blocks inlining and vectorization
+    }
+}
+
+// Better:
+fn apply_filter_generic<F: Filter + ?Sized>(filter: &F, image: &mut [u8]) {
+    for px in image.iter_mut() {
+        *px = filter.apply(*px);  // ✅ Monomorphized when the type is known
+    }
+}
+
+// SCENARIO 14: Wildcard arms on local and foreign enums
+enum Priority { High, Normal, Low }
+
+fn route(p: Priority) -> &'static str {
+    match p {
+        Priority::High => "fast",
+        _ => "default",  // ❌ Local enum: a new Priority::Urgent lands here silently
+    }
+}
+
+fn classify(err: &std::num::ParseIntError) -> &'static str {
+    use std::num::IntErrorKind;
+    match err.kind() {
+        IntErrorKind::Empty => "empty",
+        IntErrorKind::InvalidDigit => "not a number",
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => "out of range",
+        IntErrorKind::Zero => "zero",
+        _ => unreachable!(),  // ❌ All of today's variants are listed, but IntErrorKind is #[non_exhaustive]: panics on a future one
+    }
+}
+
+// Better:
+fn route_exhaustive(p: Priority) -> &'static str {
+    match p {
+        Priority::High => "fast",
+        Priority::Normal | Priority::Low => "default",  // ✅ New variants are a compile error here
+    }
+}
+
+fn classify_fallback(err: &std::num::ParseIntError) -> &'static str {
+    use std::num::IntErrorKind;
+    match err.kind() {
+        IntErrorKind::Empty => "empty",
+        IntErrorKind::InvalidDigit => "not a number",
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => "out of range",
+        IntErrorKind::Zero => "zero",
+        _ => "invalid integer",  // ✅ Conservative fallback for variants not yet known
+    }
+}
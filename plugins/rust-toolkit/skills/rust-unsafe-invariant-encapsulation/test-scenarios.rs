@@ -0,0 +1,161 @@
+// Test scenarios for rust-unsafe-invariant-encapsulation skill
+// Modules where safe code can break invariants relied on by unsafe code
+
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+use std::slice;
+
+// SCENARIO 1: Safe setter breaks an unsafe read
+pub struct RawBuf {
+    ptr: NonNull<u8>,
+    cap: usize,
+    len: usize,
+}
+
+impl RawBuf {
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    pub fn truncate_to(&mut self, len: usize) {
+        self.len = len;  // ❌ Safe code, unchecked - as_slice() now reads past the buffer
+    }
+}
+
+// SCENARIO 2: Public fields read by unsafe code
+pub struct Slab {
+    pub ptr: *mut Slot,  // ❌ Caller can swap the pointer
+    pub len: usize,      // ❌ Caller can grow len
+}
+
+impl Slab {
+    pub fn get(&self, idx: usize) -> Option<&Slot> {
+        (idx < self.len).then(|| unsafe { &*self.ptr.add(idx) })
+    }
+}
+
+// SCENARIO 3: Tuple struct constructible without validation
+pub struct Utf8Bytes(pub Vec<u8>);  // ❌ Utf8Bytes(vec![0xFF]) is safe code
+
+impl Utf8Bytes {
+    pub fn as_str(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+// SCENARIO 4: Derive bypasses the checking constructor
+#[derive(serde::Deserialize)]
+pub struct SortedIds {
+    ids: Vec<u32>,  // ❌ Deserialize accepts unsorted input
+}
+
+impl SortedIds {
+    pub fn range(&self, lo: u32, hi: u32) -> &[u32] {
+        assert!(lo <= hi);
+        let start = self.ids.partition_point(|&x| x < lo);
+        let end = self.ids.partition_point(|&x| x < hi);
+        // SAFETY: ids is sorted, so lo <= hi implies start <= end
+        unsafe { self.ids.get_unchecked(start..end) }  // ❌ Unsorted input can give start > end: UB
+    }
+}
+
+// SCENARIO 5: Invariant broken across a panic point
+impl RawBuf {
+    pub fn extend_from(&mut self, items: &[u8], f: impl Fn(u8) -> u8) {
+        let start = self.len;
+        self.len += items.len();  // ❌ Claims initialized before writing
+        for (i, &b) in items.iter().enumerate() {
+            let v = f(b);  // ❌ Panic here leaves uninitialized bytes inside len
+            unsafe { self.ptr.as_ptr().add(start + i).write(v) };
+        }
+    }
+}
+
+// SCENARIO 6: DerefMut exposes guarded data
+pub struct NonEmpty<T>(Vec<T>);
+
+impl<T> std::ops::Deref for NonEmpty<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Vec<T> { &self.0 }
+}
+
+impl<T> std::ops::DerefMut for NonEmpty<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> { &mut self.0 }  // ❌ Caller can clear()
+}
+
+impl<T> NonEmpty<T> {
+    pub fn first(&self) -> &T {
+        unsafe { self.0.get_unchecked(0) }  // ❌ UB after clear()
+    }
+}
+
+// SCENARIO 7: Unchecked access scattered across modules
+mod scoring {
+    pub fn score(board: &super::Board, moves: &[super::Move]) -> i32 {
+        moves.iter().map(|m| unsafe { *board.cells.get_unchecked(m.to) } as i32).sum()  // ❌
+    }
+}
+
+mod rules {
+    pub fn apply(board: &mut super::Board, m: super::Move) {
+        unsafe { *board.cells.get_unchecked_mut(m.to) = board.cells[m.from] };  // ❌ Again
+    }
+}
+
+// SCENARIO 8: Good - private field, checked and unsafe constructors
+pub struct GoodUtf8(Vec<u8>);
+
+impl GoodUtf8 {
+    pub fn new(bytes: Vec<u8>) -> Result<Self, std::str::Utf8Error> {
+        std::str::from_utf8(&bytes)?;
+        Ok(Self(bytes))
+    }
+
+    /// # Safety
+    /// `bytes` must be valid UTF-8.
+    pub unsafe fn new_unchecked(bytes: Vec<u8>) -> Self {
+        Self(bytes)  // ✅ Obligation moved to the caller explicitly
+    }
+}
+
+// SCENARIO 9: Good - index newtype confined to one module
+mod cells {
+    //! Invariant: `Square` is always < 64. Only this module constructs it.
+
+    #[derive(Clone, Copy)]
+    pub struct Square(u8);
+
+    impl Square {
+        pub fn new(i: u8) -> Option<Self> {
+            (i < 64).then_some(Self(i))  // ✅ Precondition proven at construction
+        }
+    }
+
+    pub struct Board {
+        cells: [u8; 64],
+    }
+
+    impl Board {
+        pub fn get(&self, sq: Square) -> u8 {
+            // SAFETY: Square < 64 by module invariant
+            unsafe { *self.cells.get_unchecked(sq.0 as usize) }  // ✅ Single unsafe site
+        }
+    }
+}
+
+// SCENARIO 10: Good - invariant restored only after the write
+pub struct Stack<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Stack<T, N> {
+    pub fn push(&mut self, v: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(v);
+        }
+        self.buf[self.len].write(v);
+        self.len += 1;  // ✅ len grows after initialization
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Test scenarios for rust-zero-copy-parsing skill
+// Parsers that should trigger allocation, lifetime, alignment, and bounds questions
+
+use nom::bytes::complete::{tag, take_until};
+use nom::IResult;
+
+// SCENARIO 1: Every field copied into an owned String
+struct LogLine {
+    timestamp: String,
+    level: String,
+    target: String,
+    message: String,
+}
+
+fn parse_line(line: &str) -> Option<LogLine> {
+    let mut parts = line.splitn(4, ' ');
+    Some(LogLine {
+        timestamp: parts.next()?.to_string(),  // ❌ Allocation per field, per line
+        level: parts.next()?.to_string(),
+        target: parts.next()?.to_string(),
+        message: parts.next()?.to_string(),
+    })
+}
+
+// SCENARIO 2: Copy just to validate UTF-8
+fn name_field(bytes: &[u8]) -> Result<String, std::string::FromUtf8Error> {
+    String::from_utf8(bytes.to_vec())  // ❌ std::str::from_utf8 borrows instead
+}
+
+// SCENARIO 3: nom leaf parser copying to escape lifetimes
+struct OwnedHeader {
+    name: Vec<u8>,
+    value: Vec<u8>,
+}
+
+fn header(input: &[u8]) -> IResult<&[u8], OwnedHeader> {
+    let (input, name) = take_until(":")(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, value) = take_until("\r\n")(input)?;
+    Ok((input, OwnedHeader { name: name.to_vec(), value: value.to_vec() }))  // ❌ Copies in leaf parser
+}
+
+// SCENARIO 4: Unaligned pointer-cast read
+fn read_u32(buf: &[u8], offset: usize) -> u32 {
+    unsafe { *(buf.as_ptr().add(offset) as *const u32) }  // ❌ UB if misaligned, host endianness
+}
+
+// SCENARIO 5: Struct overlaid on untrusted bytes
+#[repr(C)]
+struct FileHeader {
+    magic: u32,
+    version: u16,
+    flags: u16,
+}
+
+fn header_of(buf: &[u8]) -> &FileHeader {
+    unsafe { &*(buf.as_ptr() as *const FileHeader) }  // ❌ Length, alignment, endianness unchecked
+}
+
+// SCENARIO 6: Length prefix used without a bounds check
+fn read_string(buf: &[u8]) -> (&[u8], &[u8]) {
+    let len = u16::from_be_bytes([buf[0], buf[1]]) as usize;  // ❌ Panics on < 2 bytes
+    (&buf[2..2 + len], &buf[2 + len..])                       // ❌ Panics on truncated input
+}
+
+// SCENARIO 7: Allocation sized by an untrusted count
+fn read_entries(buf: &[u8]) -> Vec<Entry> {
+    let count = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+    let mut entries = Vec::with_capacity(count);  // ❌ 0xFFFF_FFFF allocates gigabytes
+    // ...
+    entries
+}
+
+// SCENARIO 8: Offset arithmetic that can overflow
+fn field_at(buf: &[u8], offset: usize, len: usize) -> Option<&[u8]> {
+    if offset + len <= buf.len() {  // ❌ offset + len can wrap in release builds
+        Some(&buf[offset..offset + len])
+    } else {
+        None
+    }
+}
+
+// SCENARIO 9: Good - borrowed output tied to input lifetime
+struct Header<'a> {
+    name: &'a [u8],
+    value: &'a [u8],
+}
+
+fn header_borrowed(input: &[u8]) -> IResult<&[u8], Header<'_>> {
+    let (input, name) = take_until(":")(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, value) = take_until("\r\n")(input)?;
+    Ok((input, Header { name, value }))  // ✅ No allocation
+}
+
+// SCENARIO 10: Good - checked length prefix and explicit endianness
+fn take_prefixed(input: &[u8]) -> Result<(&[u8], &[u8]), ParseError> {
+    let (len, rest) = input.split_first_chunk::<4>().ok_or(ParseError::Truncated)?;
+    let len = u32::from_le_bytes(*len) as usize;  // ✅ Format-defined endianness
+    if rest.len() < len {
+        return Err(ParseError::Truncated);  // ✅ Truncation is an error
+    }
+    Ok(rest.split_at(len))
+}